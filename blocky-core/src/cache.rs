@@ -0,0 +1,184 @@
+use crate::error::DownloadError;
+use crate::minecraft::download_options::DownloadOptions;
+use crate::utils::retry;
+use chrono::{DateTime, Utc};
+use reqwest::header::{HeaderName, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Metadata files are cached by their URL and revalidated with ETag/Last-Modified once they are
+// older than the configured max age
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct CacheEntry {
+    etag: Option<String>,
+    last_modified: Option<String>,
+    fetched: DateTime<Utc>,
+}
+
+// Falls back to the cached copy if the server can not be reached
+pub(crate) fn get(url: &str, download_options: &DownloadOptions) -> Result<Vec<u8>, DownloadError> {
+    let cache_path = match &download_options.cache_path {
+        Some(cache_path) => cache_path,
+        None => {
+            return retry(url, download_options, || request(url, None))?
+                .map(|(body, _)| body)
+                .ok_or_else(|| {
+                    DownloadError::HttpStatus(url.to_string(), StatusCode::NOT_MODIFIED)
+                })
+        }
+    };
+
+    let (data_path, entry_path) = cache_file_paths(cache_path, url);
+    let cached = read_entry(&entry_path).filter(|_| data_path.is_file());
+
+    if let Some(entry) = &cached {
+        let is_fresh = Utc::now()
+            .signed_duration_since(entry.fetched)
+            .to_std()
+            .map(|age| age < download_options.cache_max_age)
+            .unwrap_or(false);
+
+        if is_fresh {
+            trace!("Using cached file for: {}", url);
+            return fs::read(&data_path).map_err(DownloadError::IO);
+        }
+    }
+
+    match retry(url, download_options, || request(url, cached.as_ref())) {
+        Ok(Some((body, entry))) => {
+            trace!("Caching file: {}", url);
+            fs::create_dir_all(cache_path).map_err(DownloadError::IO)?;
+            fs::write(&data_path, &body).map_err(DownloadError::IO)?;
+            write_entry(&entry_path, &entry)?;
+
+            Ok(body)
+        }
+        Ok(None) => {
+            trace!("Cached file is still valid: {}", url);
+            if let Some(mut entry) = cached {
+                entry.fetched = Utc::now();
+                write_entry(&entry_path, &entry)?;
+            }
+
+            fs::read(&data_path).map_err(DownloadError::IO)
+        }
+        Err(err) if cached.is_some() => {
+            warn!("Using cached file for '{}': {}", url, err);
+            fs::read(&data_path).map_err(DownloadError::IO)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+// Refetches the file once if the cached copy does not match the checksum
+pub(crate) fn get_verified(
+    url: &str,
+    sha1: Option<&[u8]>,
+    download_options: &DownloadOptions,
+) -> Result<Vec<u8>, DownloadError> {
+    let data = get(url, download_options)?;
+    if sha1_matches(&data, sha1) {
+        return Ok(data);
+    }
+
+    debug!("Cached file does not match checksum: {}", url);
+    remove(url, download_options)?;
+    let data = get(url, download_options)?;
+    if sha1_matches(&data, sha1) {
+        return Ok(data);
+    }
+
+    remove(url, download_options)?;
+    Err(DownloadError::Sha1Mismatch(url.to_string()))
+}
+
+pub(crate) fn remove(url: &str, download_options: &DownloadOptions) -> Result<(), DownloadError> {
+    if let Some(cache_path) = &download_options.cache_path {
+        let (data_path, entry_path) = cache_file_paths(cache_path, url);
+        for path in [data_path, entry_path] {
+            if path.exists() {
+                fs::remove_file(path).map_err(DownloadError::IO)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Returns `None` if the server responded with 304 Not Modified
+fn request(
+    url: &str,
+    entry: Option<&CacheEntry>,
+) -> Result<Option<(Vec<u8>, CacheEntry)>, DownloadError> {
+    trace!("Requesting file: {}", url);
+
+    let mut request = reqwest::blocking::Client::new().get(url);
+    if let Some(entry) = entry {
+        if let Some(etag) = &entry.etag {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = &entry.last_modified {
+            request = request.header(IF_MODIFIED_SINCE, last_modified);
+        }
+    }
+
+    let response = request
+        .send()
+        .map_err(|err| DownloadError::from_reqwest(url, err))?;
+
+    if response.status() == StatusCode::NOT_MODIFIED {
+        return Ok(None);
+    }
+
+    let response = response
+        .error_for_status()
+        .map_err(|err| DownloadError::from_reqwest(url, err))?;
+
+    let header = |name: HeaderName| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(String::from)
+    };
+    let entry = CacheEntry {
+        etag: header(ETAG),
+        last_modified: header(LAST_MODIFIED),
+        fetched: Utc::now(),
+    };
+
+    let body = response
+        .bytes()
+        .map_err(|err| DownloadError::from_reqwest(url, err))?;
+
+    Ok(Some((body.to_vec(), entry)))
+}
+
+fn sha1_matches(data: &[u8], sha1: Option<&[u8]>) -> bool {
+    match sha1 {
+        Some(sha1) => Sha1::digest(data).as_slice() == sha1,
+        None => true,
+    }
+}
+
+fn cache_file_paths(cache_path: impl AsRef<Path>, url: &str) -> (PathBuf, PathBuf) {
+    let hash = hex::encode(Sha1::digest(url.as_bytes()));
+
+    let data_path = cache_path.as_ref().join(format!("{}.json", &hash));
+    let entry_path = cache_path.as_ref().join(format!("{}.meta", &hash));
+    (data_path, entry_path)
+}
+
+fn read_entry(entry_path: impl AsRef<Path>) -> Option<CacheEntry> {
+    let entry = fs::read_to_string(entry_path).ok()?;
+    serde_json::from_str(&entry).ok()
+}
+
+fn write_entry(entry_path: impl AsRef<Path>, entry: &CacheEntry) -> Result<(), DownloadError> {
+    let entry = serde_json::to_vec(entry).map_err(|err| DownloadError::IO(err.into()))?;
+    fs::write(entry_path, entry).map_err(DownloadError::IO)
+}
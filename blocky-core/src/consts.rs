@@ -8,6 +8,18 @@ pub const MC_ENTITLEMENTS_URL: &str = "https://api.minecraftservices.com/entitle
 pub const MC_PROFILE_URL: &str = "https://api.minecraftservices.com/minecraft/profile";
 
 pub const MC_VERSION_MANIFEST_URL: &str =
-    "https://launchermeta.mojang.com/mc/game/version_manifest.json";
+    "https://launchermeta.mojang.com/mc/game/version_manifest_v2.json";
 pub const MC_LIBRARIES_BASE_URL: &str = "https://libraries.minecraft.net";
 pub const MC_ASSETS_BASE_URL: &str = "https://resources.download.minecraft.net";
+pub const JAVA_RUNTIMES_URL: &str = "https://launchermeta.mojang.com/v1/products/java-runtime/2ec0cc96c44e5a76b9c8b7c39df7210883d12871/all.json";
+pub const DEFAULT_JAVA_COMPONENT: &str = "jre-legacy";
+pub const DEFAULT_JAVA_MAJOR_VERSION: u32 = 8;
+
+pub const FABRIC_META_URL: &str = "https://meta.fabricmc.net";
+pub const FORGE_MAVEN_URL: &str = "https://maven.minecraftforge.net";
+pub const QUILT_META_URL: &str = "https://meta.quiltmc.org";
+pub const NEOFORGE_MAVEN_URL: &str = "https://maven.neoforged.net/releases";
+
+pub const CURSEFORGE_API_URL: &str = "https://api.curseforge.com";
+pub const MODRINTH_API_URL: &str = "https://api.modrinth.com/v2";
+pub const MODRINTH_USER_AGENT: &str = concat!("blocky/", env!("CARGO_PKG_VERSION"));
@@ -0,0 +1,109 @@
+use crate::consts;
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Builder, Clone, Debug, Deserialize, Serialize)]
+pub struct Endpoints {
+    // Authentication
+    #[builder(default = "String::from(consts::MS_AUTH_CODE_URL)")]
+    pub ms_auth_code_url: String,
+    #[builder(default = "String::from(consts::MS_AUTH_TOKEN_URL)")]
+    pub ms_auth_token_url: String,
+    #[builder(default = "String::from(consts::XBL_AUTH_TOKEN_URL)")]
+    pub xbl_auth_token_url: String,
+    #[builder(default = "String::from(consts::XSTS_AUTH_TOKEN_URL)")]
+    pub xsts_auth_token_url: String,
+    #[builder(default = "String::from(consts::MC_AUTH_TOKEN_URL)")]
+    pub mc_auth_token_url: String,
+    #[builder(default = "String::from(consts::MC_ENTITLEMENTS_URL)")]
+    pub mc_entitlements_url: String,
+    #[builder(default = "String::from(consts::MC_PROFILE_URL)")]
+    pub mc_profile_url: String,
+
+    // Installation
+    #[builder(default = "String::from(consts::MC_VERSION_MANIFEST_URL)")]
+    pub version_manifest_url: String,
+    #[builder(default = "String::from(consts::MC_LIBRARIES_BASE_URL)")]
+    pub libraries_base_url: String,
+    #[builder(default = "String::from(consts::MC_ASSETS_BASE_URL)")]
+    pub assets_base_url: String,
+    #[builder(default = "String::from(consts::JAVA_RUNTIMES_URL)")]
+    pub java_runtimes_url: String,
+    // Additional (prefix, replacement) pairs applied to download URLs
+    #[builder(default)]
+    pub url_rewrites: Vec<(String, String)>,
+
+    // Mod loaders
+    #[builder(default = "String::from(consts::FABRIC_META_URL)")]
+    pub fabric_meta_url: String,
+    #[builder(default = "String::from(consts::FORGE_MAVEN_URL)")]
+    pub forge_maven_url: String,
+    #[builder(default = "String::from(consts::QUILT_META_URL)")]
+    pub quilt_meta_url: String,
+    #[builder(default = "String::from(consts::NEOFORGE_MAVEN_URL)")]
+    pub neoforge_maven_url: String,
+
+    // Mods and modpacks
+    #[builder(default = "String::from(consts::MODRINTH_API_URL)")]
+    pub modrinth_api_url: String,
+    #[builder(default = "String::from(consts::CURSEFORGE_API_URL)")]
+    pub curseforge_api_url: String,
+    // Sent as `x-api-key`, compatible stand-ins might not need one
+    #[builder(default)]
+    #[builder(setter(strip_option))]
+    pub curseforge_api_key: Option<String>,
+}
+
+impl Default for Endpoints {
+    fn default() -> Self {
+        EndpointsBuilder::default().build().unwrap()
+    }
+}
+
+impl Endpoints {
+    pub fn asset_url(&self, hash: &str) -> String {
+        let hash_part: String = hash.chars().take(2).collect();
+        format!(
+            "{}/{}/{}",
+            self.assets_base_url.trim_end_matches('/'),
+            &hash_part,
+            hash
+        )
+    }
+
+    pub fn library_url(&self, path: &str) -> String {
+        format!(
+            "{}/{}",
+            self.libraries_base_url.trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+
+    pub fn rewrite_url(&self, url: &str) -> String {
+        if let Some(path) = strip_url_prefix(url, consts::MC_LIBRARIES_BASE_URL) {
+            return self.library_url(path);
+        }
+
+        if let Some(path) = strip_url_prefix(url, consts::MC_ASSETS_BASE_URL) {
+            return format!("{}{}", self.assets_base_url.trim_end_matches('/'), path);
+        }
+
+        for (prefix, replacement) in &self.url_rewrites {
+            if let Some(path) = strip_url_prefix(url, prefix) {
+                return format!("{}{}", replacement.trim_end_matches('/'), path);
+            }
+        }
+
+        url.to_string()
+    }
+}
+
+// Only matches whole path segments, `https://example.com` is no prefix of
+// `https://example.com.evil/file`
+fn strip_url_prefix<'a>(url: &'a str, prefix: &str) -> Option<&'a str> {
+    let path = url.strip_prefix(prefix.trim_end_matches('/'))?;
+    match path.chars().next() {
+        None | Some('/') | Some('?') | Some('#') => Some(path),
+        _ => None,
+    }
+}
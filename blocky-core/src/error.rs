@@ -1,4 +1,6 @@
 use crate::minecraft::error::MinecraftError;
+use crate::modpack::error::ModpackError;
+use crate::modrinth::error::ModrinthError;
 use crate::profile::error::AuthenticationError;
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -11,6 +13,12 @@ pub enum Error {
     #[error("{0}")]
     Minecraft(MinecraftError),
 
+    #[error("{0}")]
+    Modpack(ModpackError),
+
+    #[error("{0}")]
+    Modrinth(ModrinthError),
+
     #[error("Version '{0}' is invalid")]
     Version(String),
 
@@ -55,6 +63,15 @@ pub enum DownloadError {
     #[error("The checksum does not match hash of file '{0}'")]
     Sha1Mismatch(String),
 
+    #[error("Server responded with status {1} for '{0}'")]
+    HttpStatus(String, reqwest::StatusCode),
+
+    #[error("Timed out while downloading '{0}'")]
+    Timeout(String),
+
+    #[error("Giving up on '{0}' after {1} attempts: {2}")]
+    RetriesExhausted(String, u32, Box<DownloadError>),
+
     #[error("{0}")]
     Reqwest(reqwest::Error),
 
@@ -62,6 +79,72 @@ pub enum DownloadError {
     IO(std::io::Error),
 }
 
+impl DownloadError {
+    pub(crate) fn from_reqwest(url: &str, err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            return Self::Timeout(url.to_string());
+        }
+
+        match err.status() {
+            Some(status) => Self::HttpStatus(url.to_string(), status),
+            None => Self::Reqwest(err),
+        }
+    }
+
+    pub(crate) fn from_io(url: &str, err: std::io::Error) -> Self {
+        if err.kind() == std::io::ErrorKind::TimedOut {
+            return Self::Timeout(url.to_string());
+        }
+
+        // Errors while reading the response body are wrapped reqwest errors
+        if err
+            .get_ref()
+            .map(|inner| inner.is::<reqwest::Error>())
+            .unwrap_or(false)
+        {
+            let inner = err.into_inner().unwrap();
+            return Self::from_reqwest(url, *inner.downcast::<reqwest::Error>().unwrap());
+        }
+
+        Self::IO(err)
+    }
+
+    // Timeouts, server errors and dropped connections are worth another try
+    pub fn is_transient(&self) -> bool {
+        match self {
+            DownloadError::Timeout(_) => true,
+            DownloadError::HttpStatus(_, status) => status.is_server_error(),
+            DownloadError::Reqwest(err) => {
+                err.is_connect() || err.is_timeout() || err.is_body() || is_connection_reset(err)
+            }
+            DownloadError::IO(err) => is_reset_kind(err.kind()),
+            _ => false,
+        }
+    }
+}
+
+// Dropped connections surface as IO errors somewhere in the source chain
+fn is_connection_reset(err: &reqwest::Error) -> bool {
+    let mut source = std::error::Error::source(err);
+    while let Some(err) = source {
+        if let Some(err) = err.downcast_ref::<std::io::Error>() {
+            return is_reset_kind(err.kind());
+        }
+        source = err.source();
+    }
+
+    false
+}
+
+fn is_reset_kind(kind: std::io::ErrorKind) -> bool {
+    matches!(
+        kind,
+        std::io::ErrorKind::ConnectionReset
+            | std::io::ErrorKind::ConnectionAborted
+            | std::io::ErrorKind::UnexpectedEof
+    )
+}
+
 impl From<AuthenticationError> for Error {
     fn from(err: AuthenticationError) -> Self {
         Self::Authentication(err)
@@ -74,6 +157,18 @@ impl From<MinecraftError> for Error {
     }
 }
 
+impl From<ModpackError> for Error {
+    fn from(err: ModpackError) -> Self {
+        Self::Modpack(err)
+    }
+}
+
+impl From<ModrinthError> for Error {
+    fn from(err: ModrinthError) -> Self {
+        Self::Modrinth(err)
+    }
+}
+
 impl From<DownloadError> for Error {
     fn from(err: DownloadError) -> Self {
         Self::Download(err)
@@ -1,4 +1,5 @@
 use crate::instance::{Instance, InstanceBuilder};
+use crate::loader::models::loader::{Loader, LoaderKind};
 use glib::subclass::prelude::*;
 use glib::{
     ObjectExt, ParamFlags, ParamSpec, ParamSpecBoolean, ParamSpecString, ParamSpecUInt, ToValue,
@@ -14,6 +15,9 @@ pub const UUID: &str = "uuid";
 pub const NAME: &str = "name";
 pub const DESCRIPTION: &str = "description";
 pub const VERSION: &str = "version";
+pub const CUSTOM_VERSION: &str = "custom-version";
+pub const LOADER: &str = "loader";
+pub const LOADER_VERSION: &str = "loader-version";
 pub const INSTANCE_PATH: &str = "instance-path";
 pub const LIBRARIES_PATH: &str = "libraries-path";
 pub const ASSETS_PATH: &str = "assets-path";
@@ -38,6 +42,9 @@ mod imp {
         pub name: RefCell<String>,
         pub description: RefCell<String>,
         pub version: RefCell<String>,
+        pub custom_version: RefCell<String>,
+        pub loader: RefCell<String>,
+        pub loader_version: RefCell<String>,
         pub instance_path: RefCell<String>,
         pub libraries_path: RefCell<String>,
         pub assets_path: RefCell<String>,
@@ -81,6 +88,21 @@ mod imp {
                         None,
                         ParamFlags::READWRITE,
                     ),
+                    ParamSpecString::new(
+                        CUSTOM_VERSION,
+                        "Custom Version",
+                        "Custom Version",
+                        None,
+                        ParamFlags::READWRITE,
+                    ),
+                    ParamSpecString::new(LOADER, "Loader", "Loader", None, ParamFlags::READWRITE),
+                    ParamSpecString::new(
+                        LOADER_VERSION,
+                        "Loader Version",
+                        "Loader Version",
+                        None,
+                        ParamFlags::READWRITE,
+                    ),
                     ParamSpecString::new(
                         INSTANCE_PATH,
                         "Instance Path",
@@ -199,6 +221,9 @@ mod imp {
                 NAME => *self.name.borrow_mut() = value.get().unwrap(),
                 DESCRIPTION => *self.description.borrow_mut() = value.get().unwrap(),
                 VERSION => *self.version.borrow_mut() = value.get().unwrap(),
+                CUSTOM_VERSION => *self.custom_version.borrow_mut() = value.get().unwrap(),
+                LOADER => *self.loader.borrow_mut() = value.get().unwrap(),
+                LOADER_VERSION => *self.loader_version.borrow_mut() = value.get().unwrap(),
                 INSTANCE_PATH => *self.instance_path.borrow_mut() = value.get().unwrap(),
                 LIBRARIES_PATH => *self.libraries_path.borrow_mut() = value.get().unwrap(),
                 ASSETS_PATH => *self.assets_path.borrow_mut() = value.get().unwrap(),
@@ -226,6 +251,9 @@ mod imp {
                 NAME => self.name.borrow().to_value(),
                 DESCRIPTION => self.description.borrow().to_value(),
                 VERSION => self.version.borrow().to_value(),
+                CUSTOM_VERSION => self.custom_version.borrow().to_value(),
+                LOADER => self.loader.borrow().to_value(),
+                LOADER_VERSION => self.loader_version.borrow().to_value(),
                 INSTANCE_PATH => self.instance_path.borrow().to_value(),
                 LIBRARIES_PATH => self.libraries_path.borrow().to_value(),
                 ASSETS_PATH => self.assets_path.borrow().to_value(),
@@ -281,11 +309,19 @@ impl GInstance {
 
 impl From<Instance> for GInstance {
     fn from(instance: Instance) -> Self {
+        let (loader, loader_version) = match &instance.loader {
+            Some(loader) => (loader.kind.to_string(), loader.version.clone()),
+            None => (String::new(), String::new()),
+        };
+
         glib::Object::new(&[
             (UUID, &instance.uuid.to_string()),
             (NAME, &instance.name),
             (DESCRIPTION, &instance.description.unwrap_or_default()),
             (VERSION, &instance.version),
+            (CUSTOM_VERSION, &instance.custom_version.unwrap_or_default()),
+            (LOADER, &loader),
+            (LOADER_VERSION, &loader_version),
             (INSTANCE_PATH, &instance.instance_path),
             (LIBRARIES_PATH, &instance.libraries_path),
             (ASSETS_PATH, &instance.assets_path),
@@ -317,6 +353,10 @@ impl From<GInstance> for Instance {
             }
         };
         let description = instance.property::<String>(DESCRIPTION).trim().to_string();
+        let custom_version = instance
+            .property::<String>(CUSTOM_VERSION)
+            .trim()
+            .to_string();
         let libraries_path = instance
             .property::<String>(LIBRARIES_PATH)
             .trim()
@@ -343,6 +383,16 @@ impl From<GInstance> for Instance {
         if !description.is_empty() {
             instance_builder.description(description);
         }
+        if !custom_version.is_empty() {
+            instance_builder.custom_version(custom_version);
+        }
+        let loader_version = instance.property::<String>(LOADER_VERSION);
+        if let Ok(kind) = LoaderKind::from_str(&instance.property::<String>(LOADER)) {
+            instance_builder.loader(Loader {
+                kind,
+                version: loader_version,
+            });
+        }
 
         if !libraries_path.is_empty() {
             instance_builder.libraries_path(libraries_path);
@@ -1,11 +1,17 @@
+use crate::endpoints::Endpoints;
 use crate::error;
 use crate::instance::Instance;
+use crate::minecraft::download_options::DownloadOptions;
 use crate::minecraft::models::asset_index_data::AssetIndexData;
-use crate::utils::download_file_check;
+use crate::utils::download_file_retry;
 use std::fs;
 
 impl Instance {
-    pub fn save_asset_index(&self) -> error::Result<()> {
+    pub fn save_asset_index(
+        &self,
+        endpoints: &Endpoints,
+        download_options: &DownloadOptions,
+    ) -> error::Result<()> {
         let version_data = self.read_version_data()?;
 
         if let Some(asset_index) = &version_data.asset_index {
@@ -17,7 +23,13 @@ impl Instance {
             // Download file
             indexes_path.push(format!("{}.json", &version_data.assets));
             let sha = hex::decode(&asset_index.sha1).map_err(error::Error::Sha1Decode)?;
-            download_file_check(&asset_index.url, indexes_path, Some(sha))?;
+            download_file_retry(
+                &endpoints.rewrite_url(&asset_index.url),
+                indexes_path,
+                Some(sha),
+                download_options,
+                &mut |_| {},
+            )?;
         }
 
         Ok(())
@@ -1,31 +1,78 @@
+use crate::endpoints::Endpoints;
 use crate::error;
 use crate::instance::Instance;
-use crate::minecraft::install::{install_assets, install_resources};
+use crate::minecraft::download_options::DownloadOptions;
+use crate::minecraft::install::{install_assets, install_resources, link_resources};
 use crate::minecraft::installation_update::InstallationUpdate;
+use crate::minecraft::models::asset_index_data::{AssetIndexData, AssetLayout};
+use crate::minecraft::models::version_data::VersionData;
 use crossbeam_channel::Sender;
-use std::sync::atomic::AtomicBool;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 impl Instance {
     pub fn install_assets(
         &self,
+        endpoints: &Endpoints,
+        download_options: &DownloadOptions,
         update_sender: Sender<InstallationUpdate>,
         cancel: Arc<AtomicBool>,
     ) -> error::Result<()> {
+        let version_data = self.read_version_data()?;
         let asset_index = self.read_asset_index()?;
 
-        // Check if installed as resource
-        match asset_index.map_to_resources {
-            true => install_resources(
-                &asset_index,
-                self.resources_path(),
-                self.dot_minecraft_path(),
-                update_sender,
-                cancel,
-            )?,
-            false => install_assets(&asset_index, self.assets_path(), update_sender, cancel)?,
+        install_assets(
+            &asset_index,
+            self.assets_path(),
+            endpoints,
+            download_options,
+            update_sender,
+            cancel.clone(),
+        )?;
+
+        if cancel.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+
+        // Legacy versions expect assets by name
+        if let Some(legacy_assets_path) = self.legacy_assets_path(&version_data, &asset_index) {
+            install_resources(&asset_index, self.assets_path(), &legacy_assets_path)?;
+        }
+        if asset_index.layout() == AssetLayout::Resources {
+            link_resources(self.resources_path(), self.dot_minecraft_path())?;
         }
 
         Ok(())
     }
+
+    pub(crate) fn legacy_assets_path(
+        &self,
+        version_data: &VersionData,
+        asset_index: &AssetIndexData,
+    ) -> Option<PathBuf> {
+        match asset_index.layout() {
+            AssetLayout::Objects => None,
+            AssetLayout::Virtual => Some(self.virtual_assets_path(&version_data.assets)),
+            AssetLayout::Resources => Some(self.resources_path()),
+        }
+    }
+
+    // Value for `${game_assets}`
+    pub(crate) fn game_assets_path(&self, version_data: &VersionData) -> PathBuf {
+        let asset_index = match self.read_asset_index() {
+            Ok(asset_index) => asset_index,
+            Err(_) => return self.assets_path(),
+        };
+
+        match asset_index.layout() {
+            AssetLayout::Objects => self.assets_path(),
+            AssetLayout::Virtual => self.virtual_assets_path(&version_data.assets),
+            AssetLayout::Resources => {
+                let mut path = self.dot_minecraft_path();
+                path.push("resources");
+                path
+            }
+        }
+    }
 }
@@ -1,26 +1,89 @@
+use crate::endpoints::Endpoints;
 use crate::error;
+use crate::instance::verify::is_empty_dir;
 use crate::instance::Instance;
+use crate::minecraft::install_state::{InstallComponent, InstallState};
+use crate::minecraft::models::version_data::VersionData;
+use crate::utils::DownloadTask;
+use std::fs;
 
 impl Instance {
-    // TODO: Properly check installed state
-    pub fn check_installed(&self) -> error::Result<bool> {
-        let version_data_path = self.version_data_path();
-        if !version_data_path.is_file() {
-            return Ok(false);
+    // Only checks presence and size of files, use `verify` for a full check
+    pub fn check_installed(&self) -> error::Result<InstallState> {
+        let version_data = match self.read_version_data() {
+            Ok(version_data) => version_data,
+            Err(_) => return Ok(InstallState::NotInstalled),
+        };
+
+        if version_data.id != self.version_id() {
+            return Ok(InstallState::Outdated);
+        }
+
+        let expected = self.expected_files(&version_data, &Endpoints::default())?;
+        let mut missing = vec![];
+
+        if !all_present(expected.asset_index.iter()) {
+            missing.push(InstallComponent::AssetIndex);
+            missing.push(InstallComponent::Assets);
+        } else if !all_present(expected.assets.iter()) {
+            missing.push(InstallComponent::Assets);
         }
 
-        let version_data = self.read_version_data()?;
+        if !all_present(expected.libraries.iter()) {
+            missing.push(InstallComponent::Libraries);
+        }
 
-        if version_data.downloads.is_some() {
-            let mut client_path = self.dot_minecraft_path();
-            client_path.push("bin");
-            client_path.push(format!("minecraft-{}-client.jar", &version_data.id));
+        if !expected.natives.is_empty() && is_empty_dir(&self.natives_path()) {
+            missing.push(InstallComponent::Natives);
+        }
 
-            if client_path.is_file() {
-                return Ok(true);
+        if !all_present(expected.log_config.iter()) {
+            missing.push(InstallComponent::LogConfig);
+        }
+
+        if !all_present(expected.client.iter()) {
+            missing.push(InstallComponent::Client);
+        }
+
+        if !self.installer_libraries_present(&version_data)? {
+            missing.push(InstallComponent::Loader);
+        }
+
+        match missing.is_empty() {
+            true => Ok(InstallState::Installed),
+            false => Ok(InstallState::Partial(missing)),
+        }
+    }
+
+    // Libraries without a URL are extracted or patched by the loader installer, they are
+    // missing if its processors were canceled or failed
+    fn installer_libraries_present(&self, version_data: &VersionData) -> error::Result<bool> {
+        if !matches!(&self.loader, Some(loader) if loader.kind.uses_installer()) {
+            return Ok(true);
+        }
+
+        for library in version_data.needed_libraries() {
+            if !matches!(library.artifact()?, Some(artifact) if artifact.url.is_empty()) {
+                continue;
+            }
+
+            let library_path = self.libraries_path().join(library.coordinate()?.path());
+            if !library_path.is_file() {
+                trace!("Missing loader library: {}", &library.name);
+                return Ok(false);
             }
         }
 
-        Ok(false)
+        Ok(true)
     }
 }
+
+fn all_present<'a>(mut tasks: impl Iterator<Item = &'a DownloadTask>) -> bool {
+    tasks.all(|task| match fs::metadata(&task.dest) {
+        Ok(metadata) => task
+            .size
+            .map(|size| metadata.len() == size as u64)
+            .unwrap_or(true),
+        Err(_) => false,
+    })
+}
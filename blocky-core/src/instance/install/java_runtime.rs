@@ -0,0 +1,55 @@
+use crate::endpoints::Endpoints;
+use crate::error;
+use crate::instance::Instance;
+use crate::java::runtime::{install_runtime, is_runtime_installed};
+use crate::minecraft::download_options::DownloadOptions;
+use crate::minecraft::error::MinecraftError;
+use crate::minecraft::installation_update::InstallationUpdate;
+use crossbeam_channel::Sender;
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+impl Instance {
+    // Returns false if the installation got canceled
+    pub fn install_java_runtime(
+        &self,
+        java_runtimes_path: impl AsRef<Path>,
+        endpoints: &Endpoints,
+        download_options: &DownloadOptions,
+        update_sender: Sender<InstallationUpdate>,
+        cancel: Arc<AtomicBool>,
+    ) -> error::Result<bool> {
+        let version_data = self.read_version_data()?;
+        let component = version_data.java_component();
+
+        // Launching falls back to the configured or discovered Java
+        match install_runtime(
+            component,
+            &java_runtimes_path,
+            endpoints,
+            download_options,
+            update_sender,
+            cancel,
+        ) {
+            Err(MinecraftError::JavaRuntimeUnavailable(component)) => {
+                warn!(
+                    "No managed Java runtime '{}' for this platform, skipping it",
+                    component
+                );
+                Ok(true)
+            }
+            // An installed runtime stays usable if updates can not be checked
+            Err(MinecraftError::Download(err))
+                if is_runtime_installed(&java_runtimes_path, component) =>
+            {
+                warn!(
+                    "Could not check Java runtime '{}' for updates - {}",
+                    component, err
+                );
+                Ok(true)
+            }
+            result => Ok(result?),
+        }
+    }
+}
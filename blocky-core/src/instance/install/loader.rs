@@ -0,0 +1,220 @@
+use crate::endpoints::Endpoints;
+use crate::error;
+use crate::instance::Instance;
+use crate::loader::installer::InstallerPaths;
+use crate::loader::models::loader::{Loader, LoaderKind};
+use crate::loader::{fabric, forge, installer, neoforge, quilt};
+use crate::minecraft::download_options::DownloadOptions;
+use crate::minecraft::install::client_task;
+use crate::minecraft::installation_update::InstallationUpdate;
+use crate::minecraft::launch::{check_java, get_java_exec};
+use crate::minecraft::models::library::Library;
+use crate::minecraft::models::version_data::VersionData;
+use crossbeam_channel::Sender;
+use serde_json::Value;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+impl Instance {
+    // Writes the version data of the loader, which inherits from the game version
+    pub fn save_loader_version_data(
+        &self,
+        endpoints: &Endpoints,
+        download_options: &DownloadOptions,
+    ) -> error::Result<()> {
+        let loader = match &self.loader {
+            Some(loader) => loader,
+            None => return Ok(()),
+        };
+
+        let version_data = match loader.kind {
+            LoaderKind::Fabric => {
+                fabric::profile(&self.version, &loader.version, endpoints, download_options)?
+            }
+            LoaderKind::Quilt => {
+                quilt::profile(&self.version, &loader.version, endpoints, download_options)?
+            }
+            LoaderKind::Forge | LoaderKind::NeoForge => {
+                let installer_path =
+                    self.download_loader_installer(loader, endpoints, download_options)?;
+                let install_profile = installer::read_install_profile(&installer_path)?;
+                installer::read_version_data(&installer_path, &install_profile)?
+            }
+        };
+
+        // The ID is used to find the version data again
+        let mut version_data =
+            serde_json::from_slice::<Value>(&version_data).map_err(error::Error::Serde)?;
+        let version_id = loader.version_id(&self.version);
+        version_data["id"] = Value::String(version_id.clone());
+
+        let version_data_path = self.custom_version_data_path(&version_id);
+        if let Some(parent) = version_data_path.parent() {
+            fs::create_dir_all(parent).map_err(error::Error::IO)?;
+        }
+        let version_data = serde_json::to_vec_pretty(&version_data).map_err(error::Error::Serde)?;
+        fs::write(version_data_path, version_data).map_err(error::Error::IO)?;
+
+        Ok(())
+    }
+
+    // Runs the installer of loaders which patch the game, needs the client to be installed.
+    // Returns false if the installation got canceled.
+    pub fn install_loader(
+        &self,
+        endpoints: &Endpoints,
+        download_options: &DownloadOptions,
+        update_sender: Sender<InstallationUpdate>,
+        cancel: Arc<AtomicBool>,
+    ) -> error::Result<bool> {
+        let loader = match &self.loader {
+            Some(loader) if loader.kind.uses_installer() => loader,
+            _ => return Ok(true),
+        };
+
+        let installer_path = self.download_loader_installer(loader, endpoints, download_options)?;
+        let install_profile = installer::read_install_profile(&installer_path)?;
+
+        let raw_version_data =
+            fs::read(self.custom_version_data_path(&loader.version_id(&self.version)))
+                .map_err(error::Error::IO)?;
+        let version_libraries = serde_json::from_slice::<Value>(&raw_version_data)
+            .map_err(error::Error::Serde)?
+            .get("libraries")
+            .cloned()
+            .map(serde_json::from_value::<Vec<Library>>)
+            .transpose()
+            .map_err(error::Error::Serde)?
+            .unwrap_or_default();
+
+        let version_data = self.read_version_data()?;
+        let minecraft_jar = match client_task(&version_data, self.dot_minecraft_path(), endpoints)?
+        {
+            Some(task) => task.dest,
+            None => {
+                let mut client_path = self.dot_minecraft_path();
+                client_path.push("bin");
+                client_path.push(format!(
+                    "minecraft-{}-client.jar",
+                    version_data.client_jar_id()
+                ));
+                client_path
+            }
+        };
+
+        let mut root = self.instance_path();
+        root.push("loader-installer");
+        let libraries_path = self.libraries_path();
+
+        let installed = installer::install(
+            &install_profile,
+            &version_libraries,
+            &InstallerPaths {
+                installer: &installer_path,
+                libraries: &libraries_path,
+                minecraft_jar: &minecraft_jar,
+                root: &root,
+            },
+            &self.installer_java_exec(&version_data, download_options)?,
+            endpoints,
+            download_options,
+            &update_sender,
+            &cancel,
+        )?;
+
+        Ok(installed)
+    }
+
+    // Switches to another version of the current loader and installs it
+    pub fn update_loader(
+        &mut self,
+        loader_version: &str,
+        endpoints: &Endpoints,
+        download_options: &DownloadOptions,
+        update_sender: Sender<InstallationUpdate>,
+        cancel: Arc<AtomicBool>,
+    ) -> error::Result<()> {
+        let old_loader = match &self.loader {
+            Some(loader) => loader.clone(),
+            None => return Ok(()),
+        };
+        let new_loader = Loader {
+            kind: old_loader.kind,
+            version: loader_version.to_string(),
+        };
+        debug!(
+            "Updating {} from '{}' to '{}'",
+            old_loader.kind, &old_loader.version, &new_loader.version
+        );
+
+        // The instance keeps the old loader until the new one is installed
+        let old_version_id = old_loader.version_id(&self.version);
+        let new_version_id = new_loader.version_id(&self.version);
+        let mut updated = self.clone();
+        if updated.custom_version.as_deref() == Some(old_version_id.as_str()) {
+            updated.custom_version = Some(new_version_id.clone());
+        }
+        updated.loader = Some(new_loader);
+
+        updated.full_install(endpoints, download_options, update_sender, cancel.clone())?;
+        if cancel.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+
+        // Version data of the old loader would otherwise be left behind
+        let old_version_data_path = self.custom_version_data_path(&old_version_id);
+        if old_version_id != new_version_id && old_version_data_path.is_file() {
+            fs::remove_file(old_version_data_path).map_err(error::Error::IO)?;
+        }
+        *self = updated;
+
+        Ok(())
+    }
+
+    fn download_loader_installer(
+        &self,
+        loader: &Loader,
+        endpoints: &Endpoints,
+        download_options: &DownloadOptions,
+    ) -> error::Result<PathBuf> {
+        let installer_path = match loader.kind {
+            LoaderKind::NeoForge => neoforge::download_installer(
+                &self.version,
+                &loader.version,
+                self.libraries_path(),
+                endpoints,
+                download_options,
+            )?,
+            _ => forge::download_installer(
+                &self.version,
+                &loader.version,
+                self.libraries_path(),
+                endpoints,
+                download_options,
+            )?,
+        };
+
+        Ok(installer_path)
+    }
+
+    // Processors are run with the same Java the game is launched with
+    fn installer_java_exec(
+        &self,
+        version_data: &VersionData,
+        download_options: &DownloadOptions,
+    ) -> error::Result<String> {
+        let java_exec = match self.enable_java_exec && !self.java_exec.is_empty() {
+            true => get_java_exec(&self.java_exec, None, version_data),
+            false => get_java_exec(
+                &download_options.java_exec,
+                download_options.java_runtimes_path.as_deref(),
+                version_data,
+            ),
+        };
+        check_java(&java_exec, version_data)?;
+
+        Ok(java_exec)
+    }
+}
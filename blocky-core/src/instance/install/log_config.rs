@@ -1,5 +1,7 @@
+use crate::endpoints::Endpoints;
 use crate::error;
 use crate::instance::Instance;
+use crate::minecraft::download_options::DownloadOptions;
 use crate::minecraft::install::install_log_config;
 use crate::minecraft::installation_update::InstallationUpdate;
 use crossbeam_channel::Sender;
@@ -9,6 +11,8 @@ use std::sync::Arc;
 impl Instance {
     pub fn install_log_config(
         &self,
+        endpoints: &Endpoints,
+        download_options: &DownloadOptions,
         update_sender: Sender<InstallationUpdate>,
         cancel: Arc<AtomicBool>,
     ) -> error::Result<()> {
@@ -17,6 +21,8 @@ impl Instance {
         install_log_config(
             &version_data,
             self.log_configs_path(),
+            endpoints,
+            download_options,
             update_sender,
             cancel,
         )?;
@@ -2,34 +2,98 @@ mod asset_index;
 mod assets;
 mod check;
 mod client;
+mod java_runtime;
 mod libraries;
+mod loader;
 mod log_config;
+mod modpack;
 mod version_data;
 
+use crate::endpoints::Endpoints;
 use crate::error;
 use crate::instance::Instance;
+use crate::minecraft::download_options::DownloadOptions;
 use crate::minecraft::installation_update::InstallationUpdate;
 use crossbeam_channel::Sender;
 use std::fs;
-use std::sync::atomic::AtomicBool;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 impl Instance {
     pub fn full_install(
         &self,
+        endpoints: &Endpoints,
+        download_options: &DownloadOptions,
         update_sender: Sender<InstallationUpdate>,
         cancel: Arc<AtomicBool>,
     ) -> error::Result<()> {
+        // Phases stop early once canceled, `Success` is only sent for complete installations
+        let canceled = || cancel.load(Ordering::Relaxed);
+
         // Prepare needed files
         fs::create_dir_all(self.instance_path()).map_err(error::Error::IO)?;
-        self.save_version_data()?;
-        self.save_asset_index()?;
+        self.save_version_data(endpoints, download_options)?;
+        self.save_loader_version_data(endpoints, download_options)?;
+        self.save_asset_index(endpoints, download_options)?;
+
+        // Install Java
+        if let Some(java_runtimes_path) = &download_options.java_runtimes_path {
+            if !self.install_java_runtime(
+                java_runtimes_path,
+                endpoints,
+                download_options,
+                update_sender.clone(),
+                cancel.clone(),
+            )? {
+                return Ok(());
+            }
+        }
 
         // Install resources
-        self.install_libraries(update_sender.clone(), cancel.clone())?;
-        self.install_assets(update_sender.clone(), cancel.clone())?;
-        self.install_log_config(update_sender.clone(), cancel.clone())?;
-        self.install_client(update_sender.clone(), cancel)?;
+        self.install_libraries(
+            endpoints,
+            download_options,
+            update_sender.clone(),
+            cancel.clone(),
+        )?;
+        if canceled() {
+            return Ok(());
+        }
+        self.install_assets(
+            endpoints,
+            download_options,
+            update_sender.clone(),
+            cancel.clone(),
+        )?;
+        if canceled() {
+            return Ok(());
+        }
+        self.install_log_config(
+            endpoints,
+            download_options,
+            update_sender.clone(),
+            cancel.clone(),
+        )?;
+        if canceled() {
+            return Ok(());
+        }
+        self.install_client(
+            endpoints,
+            download_options,
+            update_sender.clone(),
+            cancel.clone(),
+        )?;
+        if canceled() {
+            return Ok(());
+        }
+        if !self.install_loader(
+            endpoints,
+            download_options,
+            update_sender.clone(),
+            cancel.clone(),
+        )? {
+            return Ok(());
+        }
 
         // Done
         let _ = update_sender.send(InstallationUpdate::Success);
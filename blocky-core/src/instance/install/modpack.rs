@@ -0,0 +1,74 @@
+use crate::endpoints::Endpoints;
+use crate::error;
+use crate::instance::Instance;
+use crate::minecraft::download_options::DownloadOptions;
+use crate::minecraft::installation_update::InstallationUpdate;
+use crate::modpack::curseforge::CurseForgeReport;
+use crate::modpack::{curseforge, mrpack};
+use crossbeam_channel::Sender;
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+impl Instance {
+    // Installs the files and overrides of a `.mrpack`, then the game itself
+    pub fn install_mrpack(
+        &self,
+        pack_path: impl AsRef<Path>,
+        endpoints: &Endpoints,
+        download_options: &DownloadOptions,
+        update_sender: Sender<InstallationUpdate>,
+        cancel: Arc<AtomicBool>,
+    ) -> error::Result<()> {
+        let index = mrpack::read_index(&pack_path)?;
+
+        fs::create_dir_all(self.dot_minecraft_path()).map_err(error::Error::IO)?;
+        if !mrpack::install_files(
+            &index,
+            self.dot_minecraft_path(),
+            download_options,
+            &update_sender,
+            &cancel,
+        )? {
+            return Ok(());
+        }
+        mrpack::extract_overrides(&pack_path, self.dot_minecraft_path())?;
+
+        self.full_install(endpoints, download_options, update_sender, cancel)
+    }
+
+    // Installs the files and overrides of a CurseForge modpack zip, then the game itself.
+    // Returns None if the installation got canceled.
+    pub fn install_curseforge_pack(
+        &self,
+        pack_path: impl AsRef<Path>,
+        endpoints: &Endpoints,
+        download_options: &DownloadOptions,
+        update_sender: Sender<InstallationUpdate>,
+        cancel: Arc<AtomicBool>,
+    ) -> error::Result<Option<CurseForgeReport>> {
+        let manifest = curseforge::read_manifest(&pack_path)?;
+
+        fs::create_dir_all(self.dot_minecraft_path()).map_err(error::Error::IO)?;
+        let report = match curseforge::install_files(
+            &manifest,
+            self.dot_minecraft_path(),
+            endpoints,
+            download_options,
+            &update_sender,
+            &cancel,
+        )? {
+            Some(report) => report,
+            None => return Ok(None),
+        };
+        curseforge::extract_overrides(&manifest, &pack_path, self.dot_minecraft_path())?;
+
+        self.full_install(endpoints, download_options, update_sender, cancel.clone())?;
+        if cancel.load(Ordering::Relaxed) {
+            return Ok(None);
+        }
+
+        Ok(Some(report))
+    }
+}
@@ -1,34 +1,84 @@
+use crate::cache;
+use crate::endpoints::Endpoints;
 use crate::error;
 use crate::instance::Instance;
+use crate::minecraft::download_options::DownloadOptions;
+use crate::minecraft::error::MinecraftError;
+use crate::minecraft::inheritance::resolve_version_data;
 use crate::minecraft::models::version_data::VersionData;
 use crate::minecraft::models::version_manifest::VersionManifest;
-use crate::utils::download_file_check;
+use crate::utils::get_sha1;
+use serde_json::Value;
 use std::fs;
 
 impl Instance {
-    pub fn save_version_data(&self) -> error::Result<()> {
-        let manifest = VersionManifest::get()?;
+    pub fn save_version_data(
+        &self,
+        endpoints: &Endpoints,
+        download_options: &DownloadOptions,
+    ) -> error::Result<()> {
+        let manifest = VersionManifest::get(endpoints, download_options)?;
 
         // Figure out version
         let version_summary = manifest
             .versions
             .get(&self.version)
             .ok_or_else(|| error::Error::Version(self.version.clone()))?;
+        let remote_sha = version_summary
+            .sha1
+            .as_ref()
+            .map(hex::decode)
+            .transpose()
+            .map_err(error::Error::Sha1Decode)?;
 
         // Save version data
-        let version_data_path = self.version_data_path();
-        download_file_check(&version_summary.url, &version_data_path, None)?;
+        let version_data_url = endpoints.rewrite_url(&version_summary.url);
+        let version_data =
+            cache::get_verified(&version_data_url, remote_sha.as_deref(), download_options)?;
+        fs::write(self.version_data_path(), version_data).map_err(error::Error::IO)?;
 
         Ok(())
     }
 
+    // Resolved version data, including everything inherited by a custom version
     pub fn read_version_data(&self) -> error::Result<VersionData> {
-        let version_data_path = self.version_data_path();
+        let version_data = self.read_raw_version_data(&self.version_id())?;
+        let version_data = resolve_version_data(version_data, |id| {
+            self.read_raw_version_data(id)
+                .map_err(|_| MinecraftError::InheritedVersion(id.to_string()))
+        })?;
+
+        Ok(version_data)
+    }
+
+    fn read_raw_version_data(&self, id: &str) -> error::Result<Value> {
+        let version_data_path = match id == self.version {
+            true => self.version_data_path(),
+            false => self.custom_version_data_path(id),
+        };
 
         let version_data = fs::read_to_string(&version_data_path).map_err(error::Error::IO)?;
-        let version_data =
-            serde_json::from_str::<VersionData>(&version_data).map_err(error::Error::Serde)?;
+        serde_json::from_str::<Value>(&version_data).map_err(error::Error::Serde)
+    }
 
-        Ok(version_data)
+    // True if the version was republished since the version data was saved
+    pub fn has_updated_metadata(
+        &self,
+        endpoints: &Endpoints,
+        download_options: &DownloadOptions,
+    ) -> error::Result<bool> {
+        let manifest = VersionManifest::get(endpoints, download_options)?;
+
+        let remote_sha = match manifest
+            .versions
+            .get(&self.version)
+            .and_then(|version_summary| version_summary.sha1.as_ref())
+        {
+            Some(sha1) => hex::decode(sha1).map_err(error::Error::Sha1Decode)?,
+            None => return Ok(false),
+        };
+        let local_sha = get_sha1(self.version_data_path())?;
+
+        Ok(local_sha != remote_sha)
     }
 }
@@ -13,6 +13,7 @@ impl Instance {
             self.dot_minecraft_path(),
             self.libraries_path(),
             self.assets_path(),
+            self.game_assets_path(&version_data),
             self.natives_path(),
             self.log_configs_path(),
             options,
@@ -29,6 +30,7 @@ impl Instance {
             self.dot_minecraft_path(),
             self.libraries_path(),
             self.assets_path(),
+            self.game_assets_path(&version_data),
             self.natives_path(),
             self.log_configs_path(),
             options,
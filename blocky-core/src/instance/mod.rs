@@ -1,11 +1,14 @@
+use crate::loader::models::loader::Loader;
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 mod install;
 mod launch;
+mod mods;
 mod paths;
 mod remove;
+mod verify;
 
 #[derive(Builder, Clone, Debug, Deserialize, Serialize)]
 pub struct Instance {
@@ -18,6 +21,13 @@ pub struct Instance {
     pub description: Option<String>,
     #[builder(default)]
     pub version: String,
+    // Version inheriting from `version`, takes precedence over `loader`
+    #[builder(default)]
+    #[builder(setter(strip_option))]
+    pub custom_version: Option<String>,
+    #[builder(default)]
+    #[builder(setter(strip_option))]
+    pub loader: Option<Loader>,
     #[builder(default = "String::from(\".\")")]
     pub instance_path: String,
     #[builder(default = "String::from(\"./libraries\")")]
@@ -51,3 +61,17 @@ pub struct Instance {
     #[builder(default)]
     pub environment_variables: Vec<(String, Option<String>)>,
 }
+
+impl Instance {
+    // ID of the version which is launched
+    pub fn version_id(&self) -> String {
+        if let Some(custom_version) = &self.custom_version {
+            return custom_version.clone();
+        }
+
+        match &self.loader {
+            Some(loader) => loader.version_id(&self.version),
+            None => self.version.clone(),
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use crate::endpoints::Endpoints;
+use crate::error;
+use crate::instance::Instance;
+use crate::minecraft::download_options::DownloadOptions;
+use crate::modrinth::api;
+use crate::modrinth::error::ModrinthError;
+use crate::modrinth::models::installed_mod::InstalledMod;
+use crate::modrinth::models::mod_filter::ModFilter;
+use crate::modrinth::models::version::Version;
+use crate::modrinth::resolve::{resolve, Resolution};
+use crate::utils::{download_files, get_sha1, DownloadTask};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+
+impl Instance {
+    pub fn mod_filter(&self) -> ModFilter {
+        ModFilter::new(&self.version, self.loader.as_ref())
+    }
+
+    // Jars in the `mods` folder, identified on Modrinth by their hash. If Modrinth can not be
+    // reached the files are still listed, just without their version.
+    pub fn installed_mods(&self, endpoints: &Endpoints) -> error::Result<Vec<InstalledMod>> {
+        self.read_installed_mods(endpoints, true)
+    }
+
+    fn read_installed_mods(
+        &self,
+        endpoints: &Endpoints,
+        allow_unidentified: bool,
+    ) -> error::Result<Vec<InstalledMod>> {
+        debug!("Listing installed mods");
+
+        let mods_path = self.mods_path();
+        if !mods_path.is_dir() {
+            return Ok(vec![]);
+        }
+
+        let mut files = vec![];
+        for entry in fs::read_dir(&mods_path).map_err(error::Error::IO)? {
+            let entry = entry.map_err(error::Error::IO)?;
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            if !entry.path().is_file() || !file_name.ends_with(".jar") {
+                continue;
+            }
+
+            let sha1 = get_sha1(entry.path()).map_err(error::Error::Download)?;
+            files.push((file_name, hex::encode(sha1)));
+        }
+        files.sort();
+
+        let hashes = files
+            .iter()
+            .map(|(_, sha1)| sha1.clone())
+            .collect::<Vec<_>>();
+        let mut versions = match api::versions_from_hashes(&hashes, endpoints) {
+            Ok(versions) => versions,
+            Err(err) if !allow_unidentified => return Err(err.into()),
+            Err(err) => {
+                warn!("Could not identify installed mods - {}", err);
+                HashMap::new()
+            }
+        };
+
+        Ok(files
+            .into_iter()
+            .map(|(file_name, sha1)| InstalledMod {
+                file_name,
+                version: versions.remove(&sha1),
+            })
+            .collect())
+    }
+
+    // Installs `version` with its required dependencies, replacing other versions of the same
+    // projects once the new files are downloaded. Returns what was installed together with
+    // any warnings.
+    pub fn install_mod(
+        &self,
+        version: Version,
+        endpoints: &Endpoints,
+        download_options: &DownloadOptions,
+    ) -> error::Result<Resolution> {
+        let resolution = self.resolve_mod(version, endpoints)?;
+        self.install_resolution(&resolution, endpoints, download_options)?;
+
+        Ok(resolution)
+    }
+
+    // What installing `version` would bring, so warnings can be shown before anything changes.
+    // Fails if the installed mods can not be identified, as replaced versions would be missed.
+    pub fn resolve_mod(
+        &self,
+        version: Version,
+        endpoints: &Endpoints,
+    ) -> error::Result<Resolution> {
+        debug!("Resolving mod '{}'", &version.name);
+
+        let installed = self.read_installed_mods(endpoints, false)?;
+        let installed_versions = installed
+            .iter()
+            .filter_map(|installed| installed.version.clone())
+            .collect::<Vec<_>>();
+        let mut resolution = resolve(version, &installed_versions, &self.mod_filter(), endpoints)?;
+
+        for version in &resolution.versions {
+            let file_name = version.primary_file().map(|file| file.filename.as_str());
+            resolution.superseded.extend(
+                installed
+                    .iter()
+                    .filter(|installed| {
+                        Some(installed.file_name.as_str()) != file_name
+                            && matches!(&installed.version, Some(old) if old.project_id == version.project_id)
+                    })
+                    .map(|installed| installed.file_name.clone()),
+            );
+        }
+
+        Ok(resolution)
+    }
+
+    pub fn install_resolution(
+        &self,
+        resolution: &Resolution,
+        endpoints: &Endpoints,
+        download_options: &DownloadOptions,
+    ) -> error::Result<()> {
+        debug!("Installing {} mods", resolution.versions.len());
+
+        let mods_path = self.mods_path();
+        fs::create_dir_all(&mods_path).map_err(error::Error::IO)?;
+
+        let mut tasks = vec![];
+        for version in &resolution.versions {
+            let file = version
+                .primary_file()
+                .ok_or_else(|| ModrinthError::NoFile(version.name.clone()))?;
+            check_file_name(&file.filename)?;
+
+            tasks.push(DownloadTask {
+                url: endpoints.rewrite_url(&file.url),
+                dest: mods_path.join(&file.filename),
+                sha1: Some(hex::decode(&file.hashes.sha1).map_err(ModrinthError::Sha1Decode)?),
+                size: (file.size > 0).then_some(file.size),
+            });
+        }
+
+        download_files(&tasks, download_options, &AtomicBool::new(false), |_| {})
+            .map_err(error::Error::Download)?;
+
+        for file_name in &resolution.superseded {
+            // Another installed version might have brought a file of the same name
+            if tasks
+                .iter()
+                .any(|task| task.dest == mods_path.join(file_name))
+            {
+                continue;
+            }
+            trace!("Replacing: {}", file_name);
+            self.remove_mod(file_name)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn remove_mod(&self, file_name: &str) -> error::Result<()> {
+        debug!("Removing mod '{}'", file_name);
+        check_file_name(file_name)?;
+
+        let path = self.mods_path().join(file_name);
+        if path.is_file() {
+            fs::remove_file(path).map_err(error::Error::IO)?;
+        }
+
+        Ok(())
+    }
+}
+
+// File names come from the API and must not leave the `mods` folder
+fn check_file_name(file_name: &str) -> Result<(), ModrinthError> {
+    let mut components = Path::new(file_name).components();
+    match (components.next(), components.next()) {
+        (Some(std::path::Component::Normal(_)), None) => Ok(()),
+        _ => Err(ModrinthError::InvalidFileName(file_name.to_string())),
+    }
+}
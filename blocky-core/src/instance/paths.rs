@@ -12,6 +12,14 @@ impl Instance {
         path
     }
 
+    // Version data of custom versions and mod loaders, e.g. `versions/<id>.json`
+    pub fn custom_version_data_path(&self, id: &str) -> PathBuf {
+        let mut path = self.instance_path();
+        path.push("versions");
+        path.push(format!("{}.json", id));
+        path
+    }
+
     pub fn libraries_path(&self) -> PathBuf {
         PathBuf::from(&self.libraries_path)
     }
@@ -32,6 +40,13 @@ impl Instance {
         path
     }
 
+    pub fn virtual_assets_path(&self, assets_id: &str) -> PathBuf {
+        let mut path = self.assets_path();
+        path.push("virtual");
+        path.push(assets_id);
+        path
+    }
+
     pub fn asset_index_path(&self) -> PathBuf {
         let mut path = self.assets_path();
         path.push("indexes");
@@ -49,4 +64,10 @@ impl Instance {
         path.push(".minecraft");
         path
     }
+
+    pub fn mods_path(&self) -> PathBuf {
+        let mut path = self.dot_minecraft_path();
+        path.push("mods");
+        path
+    }
 }
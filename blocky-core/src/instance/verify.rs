@@ -0,0 +1,257 @@
+use crate::endpoints::Endpoints;
+use crate::error;
+use crate::instance::Instance;
+use crate::minecraft::download_options::DownloadOptions;
+use crate::minecraft::error::MinecraftError;
+use crate::minecraft::install::{
+    asset_tasks, client_task, download_all, extract_natives, library_tasks, link_resources,
+    log_config_task, native_files, resource_tasks,
+};
+use crate::minecraft::installation_update::{InstallationUpdate, Progress};
+use crate::minecraft::models::asset_index_data::AssetLayout;
+use crate::minecraft::models::version_data::VersionData;
+use crate::minecraft::verify_report::VerifyReport;
+use crate::utils::DownloadTask;
+use crossbeam_channel::Sender;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+type UpdateFn = fn(Progress) -> InstallationUpdate;
+
+pub(super) struct ExpectedFiles<'a> {
+    pub asset_index: Option<DownloadTask>,
+    pub libraries: Vec<DownloadTask>,
+    pub natives: Vec<(PathBuf, &'a [String])>,
+    pub assets: Vec<DownloadTask>,
+    pub log_config: Vec<DownloadTask>,
+    pub client: Vec<DownloadTask>,
+}
+
+impl Instance {
+    pub fn verify(&self) -> error::Result<VerifyReport> {
+        debug!("Verifying instance '{}'", &self.uuid);
+        let mut report = VerifyReport::default();
+
+        // Version data
+        report.checked_files += 1;
+        let version_data = match self.read_version_data() {
+            Ok(version_data) => version_data,
+            Err(_) => {
+                report.missing.push(self.version_data_path());
+                return Ok(report);
+            }
+        };
+
+        // URLs are not needed for checking files
+        let endpoints = Endpoints::default();
+        let expected = self.expected_files(&version_data, &endpoints)?;
+
+        let tasks = expected
+            .asset_index
+            .iter()
+            .chain(expected.libraries.iter())
+            .chain(expected.assets.iter())
+            .chain(expected.log_config.iter())
+            .chain(expected.client.iter());
+        for task in tasks {
+            report.check_file(task)?;
+        }
+
+        // Natives are extracted from the native jars, which have to be intact to compare them
+        let natives_broken = expected
+            .natives
+            .iter()
+            .any(|(path, _)| report.is_broken(path));
+        if !natives_broken {
+            let natives_path = self.natives_path();
+            let natives = native_files(&expected.natives, &natives_path)?;
+            for (path, data) in &natives {
+                report.check_content(path, data);
+            }
+
+            let expected_natives = natives.iter().map(|(path, _)| path).collect::<HashSet<_>>();
+            for path in files_in(&natives_path).map_err(error::Error::IO)? {
+                if !expected_natives.contains(&path) {
+                    report.extraneous.push(path);
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    pub fn repair(
+        &self,
+        endpoints: &Endpoints,
+        download_options: &DownloadOptions,
+        update_sender: Sender<InstallationUpdate>,
+        cancel: Arc<AtomicBool>,
+    ) -> error::Result<VerifyReport> {
+        debug!("Repairing instance '{}'", &self.uuid);
+
+        // Metadata is needed to know what to check
+        fs::create_dir_all(self.instance_path()).map_err(error::Error::IO)?;
+        let version_data = self.read_version_data();
+        if !matches!(version_data, Ok(version_data) if version_data.id == self.version_id()) {
+            self.save_version_data(endpoints, download_options)?;
+            self.save_loader_version_data(endpoints, download_options)?;
+        }
+        self.save_asset_index(endpoints, download_options)?;
+
+        let report = self.verify()?;
+        debug!(
+            "Found {} broken and {} extraneous files",
+            report.broken_files(),
+            report.extraneous.len()
+        );
+
+        for path in &report.extraneous {
+            trace!("Removing extraneous file: {}", path.to_string_lossy());
+            fs::remove_file(path).map_err(error::Error::IO)?;
+        }
+
+        let version_data = self.read_version_data()?;
+        let expected = self.expected_files(&version_data, endpoints)?;
+
+        let phases = [
+            (&expected.libraries, InstallationUpdate::Library as UpdateFn),
+            (&expected.assets, InstallationUpdate::Asset),
+            (&expected.log_config, InstallationUpdate::LogConfig),
+            (&expected.client, InstallationUpdate::Client),
+        ];
+
+        for (tasks, update) in phases {
+            let broken = tasks
+                .iter()
+                .filter(|task| report.is_broken(&task.dest))
+                .cloned()
+                .collect::<Vec<_>>();
+            if broken.is_empty() {
+                continue;
+            }
+
+            if !download_all(&broken, download_options, update, &update_sender, &cancel)? {
+                return Ok(report);
+            }
+        }
+
+        // Natives and resources are derived from downloaded files. Extraction skips existing
+        // files, so the natives are extracted from scratch.
+        let natives_path = self.natives_path();
+        let natives_broken = expected
+            .natives
+            .iter()
+            .any(|(path, _)| report.is_broken(path))
+            || report
+                .missing
+                .iter()
+                .chain(report.size_mismatch.iter())
+                .chain(report.hash_mismatch.iter())
+                .any(|path| path.starts_with(&natives_path));
+        if natives_broken {
+            if natives_path.is_dir() {
+                fs::remove_dir_all(&natives_path).map_err(error::Error::IO)?;
+            }
+            extract_natives(&expected.natives, &natives_path)?;
+        }
+
+        if let Ok(asset_index) = self.read_asset_index() {
+            if asset_index.layout() == AssetLayout::Resources {
+                link_resources(self.resources_path(), self.dot_minecraft_path())?;
+            }
+        }
+
+        // Processors skip outputs which are already intact
+        if !self.install_loader(
+            endpoints,
+            download_options,
+            update_sender.clone(),
+            cancel.clone(),
+        )? {
+            return Ok(report);
+        }
+
+        // Done
+        let _ = update_sender.send(InstallationUpdate::Success);
+
+        Ok(report)
+    }
+
+    pub(super) fn expected_files<'a>(
+        &self,
+        version_data: &'a VersionData,
+        endpoints: &Endpoints,
+    ) -> Result<ExpectedFiles<'a>, MinecraftError> {
+        let (libraries, natives) = library_tasks(version_data, self.libraries_path(), endpoints)?;
+
+        let mut expected = ExpectedFiles {
+            asset_index: None,
+            libraries,
+            natives,
+            assets: vec![],
+            log_config: log_config_task(version_data, self.log_configs_path(), endpoints)?
+                .into_iter()
+                .collect(),
+            client: client_task(version_data, self.dot_minecraft_path(), endpoints)?
+                .into_iter()
+                .collect(),
+        };
+
+        if let Some(asset_index) = &version_data.asset_index {
+            let mut asset_index_path = self.asset_index_path();
+            asset_index_path.push(format!("{}.json", &version_data.assets));
+
+            expected.asset_index = Some(DownloadTask {
+                url: endpoints.rewrite_url(&asset_index.url),
+                dest: asset_index_path,
+                sha1: Some(hex::decode(&asset_index.sha1).map_err(MinecraftError::Sha1Decode)?),
+                size: Some(asset_index.size),
+            });
+
+            // Assets can only be listed with a readable index
+            if let Ok(asset_index) = self.read_asset_index() {
+                expected.assets = asset_tasks(&asset_index, self.assets_path(), endpoints)?;
+
+                if let Some(legacy_assets_path) =
+                    self.legacy_assets_path(version_data, &asset_index)
+                {
+                    expected.assets.extend(resource_tasks(
+                        &asset_index,
+                        legacy_assets_path,
+                        endpoints,
+                    )?);
+                }
+            }
+        }
+
+        Ok(expected)
+    }
+}
+
+// All files below `path`, recursively
+fn files_in(path: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    if !path.is_dir() {
+        return Ok(files);
+    }
+
+    for entry in fs::read_dir(path)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(files_in(&path)?);
+        } else {
+            files.push(path);
+        }
+    }
+
+    Ok(files)
+}
+
+pub(super) fn is_empty_dir(path: &Path) -> bool {
+    fs::read_dir(path)
+        .map(|mut entries| entries.next().is_none())
+        .unwrap_or(true)
+}
@@ -0,0 +1,313 @@
+use crate::java::models::java_installation::{JavaCompatibility, JavaInstallation};
+use crate::minecraft::error::MinecraftError;
+use crate::os::Platform;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+static PROBE_CACHE: Mutex<BTreeMap<PathBuf, (SystemTime, JavaInstallation)>> =
+    Mutex::new(BTreeMap::new());
+
+// Scans common install locations, `JAVA_HOME`, `PATH` and `extra_dirs` for Java installations.
+// Every directory in `extra_dirs` is expected to contain one installation per subdirectory.
+pub fn discover_java(extra_dirs: &[PathBuf]) -> Vec<JavaInstallation> {
+    debug!("Discovering Java installations");
+
+    let mut java_execs = vec![];
+
+    for home in candidate_homes(extra_dirs) {
+        java_execs.push(home_java_exec(home));
+    }
+
+    if let Some(path) = env::var_os("PATH") {
+        for dir in env::split_paths(&path) {
+            java_execs.push(dir.join(java_exec_name()));
+        }
+    }
+
+    let mut seen = HashSet::new();
+    let mut installations = vec![];
+    for java_exec in java_execs {
+        // Links in `PATH` usually point into one of the other locations
+        let java_exec = match fs::canonicalize(&java_exec) {
+            Ok(java_exec) if java_exec.is_file() => java_exec,
+            _ => continue,
+        };
+        if !seen.insert(java_exec.clone()) {
+            continue;
+        }
+
+        match probe_java(&java_exec) {
+            Ok(installation) => {
+                trace!(
+                    "Found {} at {}",
+                    installation.display_name(),
+                    java_exec.to_string_lossy()
+                );
+                installations.push(installation);
+            }
+            Err(err) => debug!("Skipping {}: {}", java_exec.to_string_lossy(), err),
+        }
+    }
+
+    installations
+}
+
+// Reads the `release` file of the installation or falls back to running the executable
+pub fn probe_java(java_exec: impl AsRef<Path>) -> Result<JavaInstallation, MinecraftError> {
+    let java_exec = java_exec.as_ref();
+    if !java_exec.is_file() {
+        return Err(MinecraftError::JavaNotFound(
+            java_exec.to_string_lossy().to_string(),
+        ));
+    }
+
+    let properties = match read_release_file(java_exec) {
+        Some(properties) => properties,
+        None => read_java_properties(java_exec)?,
+    };
+
+    let version = properties.get("version").cloned().ok_or_else(|| {
+        MinecraftError::UnknownJavaVersion(java_exec.to_string_lossy().to_string())
+    })?;
+    let major_version = parse_major_version(&version).ok_or_else(|| {
+        MinecraftError::UnknownJavaVersion(java_exec.to_string_lossy().to_string())
+    })?;
+
+    Ok(JavaInstallation {
+        java_exec: java_exec.to_path_buf(),
+        version,
+        major_version,
+        vendor: properties.get("vendor").cloned(),
+        arch: properties.get("arch").cloned(),
+    })
+}
+
+// Results are cached until the executable is modified
+pub fn probe_java_cached(java_exec: impl AsRef<Path>) -> Result<JavaInstallation, MinecraftError> {
+    let java_exec = java_exec.as_ref();
+    let modified = fs::metadata(java_exec)
+        .and_then(|metadata| metadata.modified())
+        .map_err(|_| MinecraftError::JavaNotFound(java_exec.to_string_lossy().to_string()))?;
+
+    let mut cache = PROBE_CACHE.lock().unwrap();
+    if let Some((cached_modified, installation)) = cache.get(java_exec) {
+        if cached_modified == &modified {
+            return Ok(installation.clone());
+        }
+    }
+
+    let installation = probe_java(java_exec)?;
+    cache.insert(java_exec.to_path_buf(), (modified, installation.clone()));
+
+    Ok(installation)
+}
+
+// Resolves bare executable names like `java` through `PATH`
+pub fn resolve_java_exec(java_exec: &str) -> Option<PathBuf> {
+    let path = PathBuf::from(java_exec);
+    if path.components().count() > 1 {
+        return fs::canonicalize(path).ok();
+    }
+
+    let mut names = vec![java_exec.to_string()];
+    if Platform::current() == Platform::Windows {
+        names.push(format!("{}.exe", java_exec));
+    }
+
+    env::split_paths(&env::var_os("PATH")?)
+        .flat_map(|dir| names.iter().map(move |name| dir.join(name)))
+        .find(|path| path.is_file())
+        .and_then(|path| fs::canonicalize(path).ok())
+}
+
+// Installations matching `required_major` come first, followed by newer ones which might be
+// incompatible. Older installations can not run the game and are left out.
+pub fn rank_java(
+    installations: Vec<JavaInstallation>,
+    required_major: u32,
+) -> Vec<(JavaInstallation, JavaCompatibility)> {
+    let mut ranked = installations
+        .into_iter()
+        .map(|installation| {
+            let compatibility = installation.compatibility(required_major);
+            (installation, compatibility)
+        })
+        .filter(|(_, compatibility)| *compatibility != JavaCompatibility::Older)
+        .collect::<Vec<_>>();
+    ranked.sort_by_key(|(installation, compatibility)| {
+        (
+            *compatibility != JavaCompatibility::Exact,
+            installation.major_version,
+            !installation.is_64bit(),
+            installation.java_exec.clone(),
+        )
+    });
+
+    ranked
+}
+
+// Handles both the old `1.8.0_312` and the new `17.0.1` scheme
+pub fn parse_major_version(version: &str) -> Option<u32> {
+    let mut parts = version.split(|c: char| !c.is_ascii_digit());
+
+    match parts.next()?.parse::<u32>().ok()? {
+        1 => parts.next()?.parse().ok(),
+        major => Some(major),
+    }
+}
+
+fn candidate_homes(extra_dirs: &[PathBuf]) -> Vec<PathBuf> {
+    let mut homes = vec![];
+
+    if let Some(java_home) = env::var_os("JAVA_HOME") {
+        homes.push(PathBuf::from(java_home));
+    }
+
+    let user_home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from);
+
+    let mut parents: Vec<PathBuf> = match Platform::current() {
+        Platform::Linux => vec![
+            PathBuf::from("/usr/lib/jvm"),
+            PathBuf::from("/usr/lib64/jvm"),
+            PathBuf::from("/usr/java"),
+            PathBuf::from("/opt/java"),
+            PathBuf::from("/opt/jdk"),
+        ],
+        Platform::MacOs => {
+            let mut parents = vec![PathBuf::from("/Library/Java/JavaVirtualMachines")];
+            if let Some(user_home) = &user_home {
+                parents.push(user_home.join("Library/Java/JavaVirtualMachines"));
+            }
+            parents
+        }
+        Platform::Windows => vec![
+            PathBuf::from(r"C:\Program Files\Java"),
+            PathBuf::from(r"C:\Program Files\Eclipse Adoptium"),
+            PathBuf::from(r"C:\Program Files\Microsoft"),
+            PathBuf::from(r"C:\Program Files\Zulu"),
+        ],
+        Platform::Other => vec![],
+    };
+
+    // SDKMAN
+    let sdkman_dir = env::var_os("SDKMAN_DIR").map(PathBuf::from).or_else(|| {
+        user_home
+            .as_ref()
+            .map(|user_home| user_home.join(".sdkman"))
+    });
+    if let Some(sdkman_dir) = sdkman_dir {
+        parents.push(sdkman_dir.join("candidates").join("java"));
+    }
+
+    // Flatpak SDK extensions, e.g. `/usr/lib/sdk/openjdk17/jvm/openjdk-17`
+    for extension in child_dirs("/usr/lib/sdk") {
+        parents.push(extension.join("jvm"));
+        homes.push(extension);
+    }
+
+    parents.extend(extra_dirs.iter().cloned());
+
+    for parent in parents {
+        for home in child_dirs(parent) {
+            // macOS bundles
+            let bundle_home = home.join("Contents").join("Home");
+            match bundle_home.is_dir() {
+                true => homes.push(bundle_home),
+                false => homes.push(home),
+            }
+        }
+    }
+
+    homes
+}
+
+fn child_dirs(parent: impl AsRef<Path>) -> Vec<PathBuf> {
+    let mut dirs = fs::read_dir(parent)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .filter(|path| path.is_dir())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    dirs.sort();
+    dirs
+}
+
+fn java_exec_name() -> &'static str {
+    match Platform::current() {
+        Platform::Windows => "java.exe",
+        _ => "java",
+    }
+}
+
+fn home_java_exec(home: impl AsRef<Path>) -> PathBuf {
+    let mut path = PathBuf::from(home.as_ref());
+    path.push("bin");
+    path.push(java_exec_name());
+    path
+}
+
+// The `release` file lives in the installation root, which is one level higher for the JRE
+// bundled with Java 8 JDKs
+fn read_release_file(java_exec: &Path) -> Option<HashMap<String, String>> {
+    let home = java_exec.parent()?.parent()?;
+    let release = fs::read_to_string(home.join("release"))
+        .ok()
+        .or_else(|| fs::read_to_string(home.parent()?.join("release")).ok())?;
+
+    let values = release
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.trim(), value.trim().trim_matches('"').to_string()))
+        .collect::<HashMap<_, _>>();
+
+    let mut properties = HashMap::new();
+    properties.insert("version".to_string(), values.get("JAVA_VERSION")?.clone());
+    if let Some(vendor) = values.get("IMPLEMENTOR") {
+        properties.insert("vendor".to_string(), vendor.clone());
+    }
+    if let Some(arch) = values.get("OS_ARCH") {
+        properties.insert("arch".to_string(), arch.clone());
+    }
+
+    Some(properties)
+}
+
+fn read_java_properties(java_exec: &Path) -> Result<HashMap<String, String>, MinecraftError> {
+    trace!("Reading properties of {}", java_exec.to_string_lossy());
+    let output = Command::new(java_exec)
+        .arg("-XshowSettings:properties")
+        .arg("-version")
+        .output()
+        .map_err(MinecraftError::IO)?;
+
+    // Properties are printed to stderr
+    let output = String::from_utf8_lossy(&output.stderr);
+    let values = output
+        .lines()
+        .filter_map(|line| line.split_once(" = "))
+        .map(|(key, value)| (key.trim(), value.trim().to_string()))
+        .collect::<HashMap<_, _>>();
+
+    let mut properties = HashMap::new();
+    for (key, property) in [
+        ("version", "java.version"),
+        ("vendor", "java.vendor"),
+        ("arch", "os.arch"),
+    ] {
+        if let Some(value) = values.get(property) {
+            properties.insert(key.to_string(), value.clone());
+        }
+    }
+
+    Ok(properties)
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::path::PathBuf;
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct JavaInstallation {
+    pub java_exec: PathBuf,
+    pub version: String,
+    pub major_version: u32,
+    pub vendor: Option<String>,
+    pub arch: Option<String>,
+}
+
+impl JavaInstallation {
+    pub fn is_64bit(&self) -> bool {
+        self.arch
+            .as_ref()
+            .map(|arch| arch.contains("64"))
+            .unwrap_or(true)
+    }
+
+    pub fn compatibility(&self, required_major: u32) -> JavaCompatibility {
+        match self.major_version.cmp(&required_major) {
+            Ordering::Equal => JavaCompatibility::Exact,
+            Ordering::Greater => JavaCompatibility::Newer,
+            Ordering::Less => JavaCompatibility::Older,
+        }
+    }
+
+    pub fn display_name(&self) -> String {
+        match &self.vendor {
+            Some(vendor) => format!("Java {} ({})", &self.version, vendor),
+            None => format!("Java {}", &self.version),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JavaCompatibility {
+    Exact,
+    // Old game versions can break on newer Java releases
+    Newer,
+    // Unable to run the game
+    Older,
+}
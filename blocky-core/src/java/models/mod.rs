@@ -0,0 +1,3 @@
+pub mod java_installation;
+pub mod runtime_files;
+pub mod runtime_manifest;
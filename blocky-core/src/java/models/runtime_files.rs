@@ -0,0 +1,53 @@
+use crate::cache;
+use crate::endpoints::Endpoints;
+use crate::minecraft::download_options::DownloadOptions;
+use crate::minecraft::error::MinecraftError;
+use crate::minecraft::models::file::File;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// Files of a single runtime component by their relative path
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RuntimeFiles {
+    pub files: HashMap<String, RuntimeFile>,
+}
+
+impl RuntimeFiles {
+    pub fn get(
+        manifest: &File,
+        endpoints: &Endpoints,
+        download_options: &DownloadOptions,
+    ) -> Result<Self, MinecraftError> {
+        let sha1 = hex::decode(&manifest.sha1).map_err(MinecraftError::Sha1Decode)?;
+        let response = cache::get_verified(
+            &endpoints.rewrite_url(&manifest.url),
+            Some(&sha1),
+            download_options,
+        )
+        .map_err(MinecraftError::Download)?;
+        let files =
+            serde_json::from_slice::<RuntimeFiles>(&response).map_err(MinecraftError::Serde)?;
+
+        Ok(files)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum RuntimeFile {
+    File {
+        #[serde(default)]
+        executable: bool,
+        downloads: RuntimeDownloads,
+    },
+    Directory,
+    Link {
+        target: String,
+    },
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RuntimeDownloads {
+    pub raw: File,
+    pub lzma: Option<File>,
+}
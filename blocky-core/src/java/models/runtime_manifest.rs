@@ -0,0 +1,61 @@
+use crate::cache;
+use crate::endpoints::Endpoints;
+use crate::minecraft::download_options::DownloadOptions;
+use crate::minecraft::error::MinecraftError;
+use crate::minecraft::models::file::File;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// Available runtimes by platform and component
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct RuntimeManifest {
+    pub platforms: HashMap<String, HashMap<String, Vec<RuntimeSummary>>>,
+}
+
+impl RuntimeManifest {
+    pub fn get(
+        endpoints: &Endpoints,
+        download_options: &DownloadOptions,
+    ) -> Result<Self, MinecraftError> {
+        let response = cache::get(&endpoints.java_runtimes_url, download_options)
+            .map_err(MinecraftError::Download)?;
+        let manifest =
+            serde_json::from_slice::<RuntimeManifest>(&response).map_err(MinecraftError::Serde)?;
+
+        Ok(manifest)
+    }
+
+    pub fn runtime(&self, component: &str) -> Option<&RuntimeSummary> {
+        self.platforms
+            .get(runtime_platform()?)?
+            .get(component)?
+            .first()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RuntimeSummary {
+    pub manifest: File,
+    pub version: RuntimeVersion,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RuntimeVersion {
+    pub name: String,
+    pub released: DateTime<Utc>,
+}
+
+fn runtime_platform() -> Option<&'static str> {
+    match (std::env::consts::OS, std::env::consts::ARCH) {
+        ("linux", "x86_64") => Some("linux"),
+        ("linux", "x86") => Some("linux-i386"),
+        ("macos", "x86_64") => Some("mac-os"),
+        ("macos", "aarch64") => Some("mac-os-arm64"),
+        ("windows", "x86_64") => Some("windows-x64"),
+        ("windows", "x86") => Some("windows-x86"),
+        ("windows", "aarch64") => Some("windows-arm64"),
+        _ => None,
+    }
+}
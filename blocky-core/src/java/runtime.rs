@@ -0,0 +1,183 @@
+use crate::endpoints::Endpoints;
+use crate::java::models::runtime_files::{RuntimeFile, RuntimeFiles};
+use crate::java::models::runtime_manifest::RuntimeManifest;
+use crate::minecraft::download_options::DownloadOptions;
+use crate::minecraft::error::MinecraftError;
+use crate::minecraft::install::download_all;
+use crate::minecraft::installation_update::InstallationUpdate;
+use crate::os::Platform;
+use crate::utils::DownloadTask;
+use crossbeam_channel::Sender;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+// Runtimes are stored by component, e.g. `<runtimes>/java-runtime-gamma`
+pub fn runtime_path(runtimes_path: impl AsRef<Path>, component: &str) -> PathBuf {
+    let mut path = PathBuf::from(runtimes_path.as_ref());
+    path.push(component);
+    path
+}
+
+pub fn java_exec_path(runtimes_path: impl AsRef<Path>, component: &str) -> PathBuf {
+    let mut path = runtime_path(runtimes_path, component);
+    match Platform::current() {
+        Platform::MacOs => {
+            path.push("jre.bundle");
+            path.push("Contents");
+            path.push("Home");
+            path.push("bin");
+            path.push("java");
+        }
+        Platform::Windows => {
+            path.push("bin");
+            path.push("javaw.exe");
+        }
+        _ => {
+            path.push("bin");
+            path.push("java");
+        }
+    }
+    path
+}
+
+// Marker which is written once all files of a runtime are installed, contains the checksum of
+// the runtime manifest they were installed from
+fn installed_marker_path(runtimes_path: impl AsRef<Path>, component: &str) -> PathBuf {
+    let mut path = runtime_path(runtimes_path, component);
+    path.push(".installed");
+    path
+}
+
+pub fn is_runtime_installed(runtimes_path: impl AsRef<Path>, component: &str) -> bool {
+    installed_marker_path(&runtimes_path, component).is_file()
+        && java_exec_path(&runtimes_path, component).is_file()
+}
+
+// Installed from the manifest with checksum `manifest_sha1`, so there are no updates
+fn is_runtime_current(
+    runtimes_path: impl AsRef<Path>,
+    component: &str,
+    manifest_sha1: &str,
+) -> bool {
+    is_runtime_installed(&runtimes_path, component)
+        && fs::read_to_string(installed_marker_path(&runtimes_path, component))
+            .map(|installed_sha1| installed_sha1.trim() == manifest_sha1)
+            .unwrap_or(false)
+}
+
+// Installs the runtime or updates it to the current manifest. Returns false if the installation
+// got canceled.
+pub fn install_runtime(
+    component: &str,
+    runtimes_path: impl AsRef<Path>,
+    endpoints: &Endpoints,
+    download_options: &DownloadOptions,
+    update_sender: Sender<InstallationUpdate>,
+    cancel: Arc<AtomicBool>,
+) -> Result<bool, MinecraftError> {
+    debug!("Install Java runtime");
+    trace!("Component: {}", component);
+    trace!(
+        "Runtimes Path: {}",
+        runtimes_path.as_ref().to_string_lossy()
+    );
+
+    let runtime_manifest = RuntimeManifest::get(endpoints, download_options)?;
+    let runtime = runtime_manifest
+        .runtime(component)
+        .ok_or_else(|| MinecraftError::JavaRuntimeUnavailable(component.to_string()))?;
+    trace!("Runtime Version: {}", &runtime.version.name);
+
+    if is_runtime_current(&runtimes_path, component, &runtime.manifest.sha1) {
+        debug!("Java runtime '{}' is up to date", component);
+        return Ok(true);
+    }
+
+    // Not usable until all files of the new version are installed
+    let marker_path = installed_marker_path(&runtimes_path, component);
+    if marker_path.is_file() {
+        fs::remove_file(&marker_path).map_err(MinecraftError::IO)?;
+    }
+
+    let runtime_files = RuntimeFiles::get(&runtime.manifest, endpoints, download_options)?;
+    let runtime_path = runtime_path(&runtimes_path, component);
+
+    let mut tasks = vec![];
+    let mut executables = vec![];
+    let mut links = vec![];
+    for (name, file) in &runtime_files.files {
+        let path = runtime_path.join(name);
+
+        match file {
+            RuntimeFile::Directory => fs::create_dir_all(&path).map_err(MinecraftError::IO)?,
+            RuntimeFile::File {
+                executable,
+                downloads,
+            } => {
+                tasks.push(DownloadTask {
+                    url: endpoints.rewrite_url(&downloads.raw.url),
+                    dest: path.clone(),
+                    sha1: Some(
+                        hex::decode(&downloads.raw.sha1).map_err(MinecraftError::Sha1Decode)?,
+                    ),
+                    size: Some(downloads.raw.size),
+                });
+
+                if *executable {
+                    executables.push(path);
+                }
+            }
+            RuntimeFile::Link { target } => links.push((path, target)),
+        }
+    }
+
+    // Download all files
+    if !download_all(
+        &tasks,
+        download_options,
+        InstallationUpdate::JavaRuntime,
+        &update_sender,
+        &cancel,
+    )? {
+        return Ok(false);
+    }
+
+    for path in executables {
+        set_executable(&path)?;
+    }
+
+    for (path, target) in links {
+        if path.symlink_metadata().is_ok() {
+            continue;
+        }
+
+        trace!("Creating symlink for {}", path.to_string_lossy());
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(MinecraftError::IO)?;
+        }
+        symlink::symlink_auto(target, &path).map_err(MinecraftError::IO)?;
+    }
+
+    fs::create_dir_all(&runtime_path).map_err(MinecraftError::IO)?;
+    fs::write(marker_path, &runtime.manifest.sha1).map_err(MinecraftError::IO)?;
+
+    Ok(true)
+}
+
+#[cfg(unix)]
+fn set_executable(path: &Path) -> Result<(), MinecraftError> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mut permissions = fs::metadata(path)
+        .map_err(MinecraftError::IO)?
+        .permissions();
+    permissions.set_mode(permissions.mode() | 0o755);
+    fs::set_permissions(path, permissions).map_err(MinecraftError::IO)
+}
+
+#[cfg(not(unix))]
+fn set_executable(_path: &Path) -> Result<(), MinecraftError> {
+    Ok(())
+}
@@ -1,15 +1,24 @@
 #[macro_use]
 extern crate log;
 
+mod cache;
 #[allow(dead_code)]
 mod consts;
 mod either;
 mod error;
 mod os;
+mod store;
+#[cfg(test)]
+mod test_server;
 mod utils;
 
+pub mod endpoints;
 pub mod instance;
+pub mod java;
+pub mod loader;
 pub mod minecraft;
+pub mod modpack;
+pub mod modrinth;
 pub mod profile;
 
 #[cfg(feature = "gobject")]
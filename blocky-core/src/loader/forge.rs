@@ -0,0 +1,88 @@
+use crate::endpoints::Endpoints;
+use crate::loader::maven;
+use crate::minecraft::download_options::DownloadOptions;
+use crate::minecraft::error::MinecraftError;
+use crate::minecraft::models::maven_coordinate::MavenCoordinate;
+use std::path::{Path, PathBuf};
+
+const FORGE_GROUP: &str = "net.minecraftforge";
+const FORGE_ARTIFACT: &str = "forge";
+// Installers for older game versions use a format which is not supported
+const MIN_MINOR_VERSION: u32 = 13;
+
+// Forge versions which support `game_version`, newest first
+pub fn loader_versions(
+    game_version: &str,
+    endpoints: &Endpoints,
+    download_options: &DownloadOptions,
+) -> Result<Vec<String>, MinecraftError> {
+    debug!("Getting Forge versions");
+    trace!("Game Version: {}", game_version);
+
+    if !supports(game_version) {
+        return Ok(vec![]);
+    }
+
+    // Versions are prefixed with the game version, e.g. `1.19.2-43.1.1`
+    let prefix = format!("{}-", game_version);
+    let mut versions = maven::metadata_versions(
+        &endpoints.forge_maven_url,
+        FORGE_GROUP,
+        FORGE_ARTIFACT,
+        download_options,
+    )?
+    .into_iter()
+    .filter_map(|version| version.strip_prefix(&prefix).map(String::from))
+    .collect::<Vec<_>>();
+    maven::sort_newest_first(&mut versions);
+
+    Ok(versions)
+}
+
+// Release versions look like `1.19.2`, the minor version decides
+fn supports(game_version: &str) -> bool {
+    let mut parts = game_version.split('.');
+    if parts.next() != Some("1") {
+        return false;
+    }
+
+    parts
+        .next()
+        .map(|minor| {
+            minor
+                .chars()
+                .take_while(|c| c.is_ascii_digit())
+                .collect::<String>()
+        })
+        .and_then(|minor| minor.parse::<u32>().ok())
+        .is_some_and(|minor| minor >= MIN_MINOR_VERSION)
+}
+
+// Downloads the installer into the libraries, e.g.
+// `net/minecraftforge/forge/1.19.2-43.1.1/forge-1.19.2-43.1.1-installer.jar`
+pub fn download_installer(
+    game_version: &str,
+    loader_version: &str,
+    libraries_path: impl AsRef<Path>,
+    endpoints: &Endpoints,
+    download_options: &DownloadOptions,
+) -> Result<PathBuf, MinecraftError> {
+    debug!("Downloading Forge installer");
+    trace!("Game Version: {}", game_version);
+    trace!("Loader Version: {}", loader_version);
+
+    let coordinate = MavenCoordinate {
+        group: FORGE_GROUP.to_string(),
+        artifact: FORGE_ARTIFACT.to_string(),
+        version: format!("{}-{}", game_version, loader_version),
+        classifier: Some("installer".to_string()),
+        extension: String::from("jar"),
+    };
+
+    maven::download_artifact(
+        &endpoints.forge_maven_url,
+        &coordinate,
+        libraries_path,
+        download_options,
+    )
+}
@@ -0,0 +1,391 @@
+use crate::endpoints::Endpoints;
+use crate::loader::models::install_profile::{InstallProfile, Processor};
+use crate::minecraft::download_options::DownloadOptions;
+use crate::minecraft::error::MinecraftError;
+use crate::minecraft::install::download_all;
+use crate::minecraft::installation_update::{InstallationUpdate, Progress};
+use crate::minecraft::models::library::Library;
+use crate::minecraft::models::maven_coordinate::MavenCoordinate;
+use crate::os::Platform;
+use crate::utils::{enclosed_path, get_sha1, DownloadTask};
+use crossbeam_channel::Sender;
+use std::collections::HashMap;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// Installers of Forge and its forks share the same format: an `install_profile.json` with the
+// libraries and processors, the version data and a local maven repository in `maven/`
+
+pub struct InstallerPaths<'a> {
+    pub installer: &'a Path,
+    pub libraries: &'a Path,
+    pub minecraft_jar: &'a Path,
+    // Working directory of the processors, removed once they are done
+    pub root: &'a Path,
+}
+
+pub fn read_install_profile(
+    installer_path: impl AsRef<Path>,
+) -> Result<InstallProfile, MinecraftError> {
+    let install_profile = read_entry(&installer_path, "install_profile.json")?;
+
+    // Installers before 1.13 use a different format
+    serde_json::from_slice::<InstallProfile>(&install_profile).map_err(|err| {
+        MinecraftError::LoaderInstaller(format!("Unsupported install profile: {}", err))
+    })
+}
+
+pub fn read_version_data(
+    installer_path: impl AsRef<Path>,
+    install_profile: &InstallProfile,
+) -> Result<Vec<u8>, MinecraftError> {
+    read_entry(installer_path, install_profile.json.trim_start_matches('/'))
+}
+
+// Installs the libraries of the installer and the version data, then runs the processors
+#[allow(clippy::too_many_arguments)]
+pub fn install(
+    install_profile: &InstallProfile,
+    version_libraries: &[Library],
+    paths: &InstallerPaths,
+    java_exec: &str,
+    endpoints: &Endpoints,
+    download_options: &DownloadOptions,
+    update_sender: &Sender<InstallationUpdate>,
+    cancel: &AtomicBool,
+) -> Result<bool, MinecraftError> {
+    debug!("Running mod loader installer");
+    trace!("Installer: {}", paths.installer.to_string_lossy());
+    trace!("Version: {}", &install_profile.version);
+
+    let libraries = install_profile
+        .libraries
+        .iter()
+        .chain(version_libraries.iter())
+        .filter(|library| library.check_use())
+        .collect::<Vec<_>>();
+    if !install_libraries(
+        &libraries,
+        paths,
+        endpoints,
+        download_options,
+        update_sender,
+        cancel,
+    )? {
+        return Ok(false);
+    }
+
+    let data = build_data(install_profile, paths)?;
+    let processors = install_profile
+        .processors
+        .iter()
+        .filter(|processor| processor.runs_on_client())
+        .collect::<Vec<_>>();
+
+    for (i, processor) in processors.iter().enumerate() {
+        if cancel.load(Ordering::Relaxed) {
+            let _ = update_sender.send(InstallationUpdate::Cancel);
+            return Ok(false);
+        }
+
+        let _ = update_sender.send(InstallationUpdate::Loader(Progress {
+            total_files: processors.len(),
+            current_file: i + 1,
+            current_file_url: processor.jar.clone(),
+            ..Default::default()
+        }));
+
+        run_processor(processor, &data, paths, java_exec)?;
+    }
+
+    // Files extracted from the installer are only needed by the processors
+    if paths.root.is_dir() {
+        fs::remove_dir_all(paths.root).map_err(MinecraftError::IO)?;
+    }
+
+    Ok(true)
+}
+
+// Libraries without a URL are either shipped in the installer or created by the processors
+fn install_libraries(
+    libraries: &[&Library],
+    paths: &InstallerPaths,
+    endpoints: &Endpoints,
+    download_options: &DownloadOptions,
+    update_sender: &Sender<InstallationUpdate>,
+    cancel: &AtomicBool,
+) -> Result<bool, MinecraftError> {
+    let mut tasks = vec![];
+
+    for library in libraries {
+        let artifact = match library.artifact()? {
+            Some(artifact) => artifact,
+            None => continue,
+        };
+        let coordinate = library.coordinate()?;
+        let library_path = paths.libraries.join(coordinate.path());
+
+        if !artifact.url.is_empty() {
+            tasks.push(DownloadTask {
+                url: endpoints.rewrite_url(&artifact.url),
+                dest: library_path,
+                sha1: match artifact.sha1.is_empty() {
+                    true => None,
+                    false => Some(hex::decode(&artifact.sha1).map_err(MinecraftError::Sha1Decode)?),
+                },
+                size: (artifact.size > 0).then_some(artifact.size),
+            });
+            continue;
+        }
+
+        if library_path.is_file() {
+            continue;
+        }
+        let entry = format!("maven/{}", coordinate.url_path());
+        if has_entry(paths.installer, &entry)? {
+            trace!("Extracting library: {}", &library.name);
+            extract_entry(paths.installer, &entry, &library_path)?;
+        }
+    }
+
+    download_all(
+        &tasks,
+        download_options,
+        InstallationUpdate::Library,
+        update_sender,
+        cancel,
+    )
+}
+
+// Values for `{KEY}` placeholders in processor arguments
+fn build_data(
+    install_profile: &InstallProfile,
+    paths: &InstallerPaths,
+) -> Result<HashMap<String, String>, MinecraftError> {
+    let mut data = HashMap::new();
+
+    for (key, entry) in &install_profile.data {
+        let value = &entry.client;
+        let value = if let Some(literal) = value
+            .strip_prefix('\'')
+            .and_then(|value| value.strip_suffix('\''))
+        {
+            literal.to_string()
+        } else if value.starts_with('[') {
+            library_path(value, paths.libraries)?
+        } else if let Some(entry) = value.strip_prefix('/') {
+            // Entries of the installer must stay inside its working directory
+            let path = enclosed_path(paths.root, entry).ok_or_else(|| {
+                MinecraftError::LoaderInstaller(format!("Unsafe data entry: {}", value))
+            })?;
+            extract_entry(paths.installer, entry, &path)?;
+            path_string(&path)
+        } else {
+            value.clone()
+        };
+
+        data.insert(key.clone(), value);
+    }
+
+    data.insert("SIDE".to_string(), "client".to_string());
+    data.insert(
+        "MINECRAFT_JAR".to_string(),
+        path_string(paths.minecraft_jar),
+    );
+    data.insert(
+        "MINECRAFT_VERSION".to_string(),
+        install_profile.minecraft.clone(),
+    );
+    data.insert("ROOT".to_string(), path_string(paths.root));
+    data.insert("INSTALLER".to_string(), path_string(paths.installer));
+    data.insert("LIBRARY_DIR".to_string(), path_string(paths.libraries));
+
+    Ok(data)
+}
+
+fn run_processor(
+    processor: &Processor,
+    data: &HashMap<String, String>,
+    paths: &InstallerPaths,
+    java_exec: &str,
+) -> Result<(), MinecraftError> {
+    debug!("Running processor: {}", &processor.jar);
+
+    let outputs = processor
+        .outputs
+        .iter()
+        .map(|(path, sha1)| {
+            Ok((
+                replace_tokens(path, data, paths)?,
+                replace_tokens(sha1, data, paths)?,
+            ))
+        })
+        .collect::<Result<Vec<_>, MinecraftError>>()?;
+    if !outputs.is_empty() && outputs_match(&outputs)? {
+        debug!("Outputs are up to date, skipping processor");
+        return Ok(());
+    }
+
+    let jar_path = library_path(&format!("[{}]", &processor.jar), paths.libraries)?;
+    let main_class = main_class(&jar_path)?.ok_or_else(|| {
+        MinecraftError::LoaderInstaller(format!("'{}' has no main class", &processor.jar))
+    })?;
+
+    let mut classpath = vec![jar_path];
+    for library in &processor.classpath {
+        classpath.push(library_path(&format!("[{}]", library), paths.libraries)?);
+    }
+
+    let args = processor
+        .args
+        .iter()
+        .map(|arg| replace_tokens(arg, data, paths))
+        .collect::<Result<Vec<_>, MinecraftError>>()?;
+
+    let mut command = Command::new(java_exec);
+    command
+        .current_dir(paths.root)
+        .arg("-cp")
+        .arg(classpath.join(&Platform::current().classpath_seperator().to_string()))
+        .arg(main_class)
+        .args(args);
+    trace!("{:?}", command);
+
+    let output = command.output().map_err(MinecraftError::IO)?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(MinecraftError::ProcessorFailed(
+            processor.jar.clone(),
+            stderr.lines().last().unwrap_or_default().to_string(),
+        ));
+    }
+
+    for (path, sha1) in &outputs {
+        if !output_matches(path, sha1)? {
+            return Err(MinecraftError::ProcessorOutput(path.clone()));
+        }
+    }
+
+    Ok(())
+}
+
+// Arguments are either a library or contain `{KEY}` placeholders
+fn replace_tokens(
+    value: &str,
+    data: &HashMap<String, String>,
+    paths: &InstallerPaths,
+) -> Result<String, MinecraftError> {
+    if value.starts_with('[') && value.ends_with(']') {
+        return library_path(value, paths.libraries);
+    }
+    if let Some(literal) = value
+        .strip_prefix('\'')
+        .and_then(|value| value.strip_suffix('\''))
+    {
+        return Ok(literal.to_string());
+    }
+
+    let mut result = String::new();
+    let mut rest = value;
+    while let Some(start) = rest.find('{') {
+        let end = rest[start..]
+            .find('}')
+            .map(|end| start + end)
+            .ok_or_else(|| {
+                MinecraftError::LoaderInstaller(format!("Unclosed placeholder in '{}'", value))
+            })?;
+        let key = &rest[start + 1..end];
+        let replacement = data.get(key).ok_or_else(|| {
+            MinecraftError::LoaderInstaller(format!("Unknown data entry '{}'", key))
+        })?;
+
+        result.push_str(&rest[..start]);
+        result.push_str(replacement);
+        rest = &rest[end + 1..];
+    }
+    result.push_str(rest);
+
+    Ok(result)
+}
+
+fn library_path(value: &str, libraries_path: &Path) -> Result<String, MinecraftError> {
+    let coordinate = value
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse::<MavenCoordinate>()?;
+
+    Ok(path_string(libraries_path.join(coordinate.path())))
+}
+
+fn outputs_match(outputs: &[(String, String)]) -> Result<bool, MinecraftError> {
+    for (path, sha1) in outputs {
+        if !output_matches(path, sha1)? {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+fn output_matches(path: &str, sha1: &str) -> Result<bool, MinecraftError> {
+    if !Path::new(path).is_file() {
+        return Ok(false);
+    }
+
+    let expected = hex::decode(sha1).map_err(MinecraftError::Sha1Decode)?;
+    let actual = get_sha1(path).map_err(MinecraftError::Download)?;
+
+    Ok(actual == expected)
+}
+
+fn main_class(jar_path: impl AsRef<Path>) -> Result<Option<String>, MinecraftError> {
+    let manifest = read_entry(jar_path, "META-INF/MANIFEST.MF")?;
+    let manifest = String::from_utf8_lossy(&manifest);
+
+    Ok(manifest
+        .lines()
+        .find_map(|line| line.strip_prefix("Main-Class:"))
+        .map(|main_class| main_class.trim().to_string()))
+}
+
+fn open_archive(path: impl AsRef<Path>) -> Result<zip::ZipArchive<fs::File>, MinecraftError> {
+    let file = fs::File::open(path).map_err(MinecraftError::IO)?;
+    zip::ZipArchive::new(file).map_err(MinecraftError::Extract)
+}
+
+fn has_entry(archive_path: impl AsRef<Path>, name: &str) -> Result<bool, MinecraftError> {
+    let mut archive = open_archive(archive_path)?;
+    let has_entry = archive.by_name(name).is_ok();
+
+    Ok(has_entry)
+}
+
+fn read_entry(archive_path: impl AsRef<Path>, name: &str) -> Result<Vec<u8>, MinecraftError> {
+    let mut archive = open_archive(&archive_path)?;
+    let mut entry = archive.by_name(name).map_err(MinecraftError::Extract)?;
+
+    let mut data = vec![];
+    entry.read_to_end(&mut data).map_err(MinecraftError::IO)?;
+
+    Ok(data)
+}
+
+fn extract_entry(
+    archive_path: impl AsRef<Path>,
+    name: &str,
+    dest: impl AsRef<Path>,
+) -> Result<(), MinecraftError> {
+    let data = read_entry(archive_path, name)?;
+
+    if let Some(parent) = dest.as_ref().parent() {
+        fs::create_dir_all(parent).map_err(MinecraftError::IO)?;
+    }
+    fs::write(dest, data).map_err(MinecraftError::IO)
+}
+
+fn path_string(path: impl AsRef<Path>) -> String {
+    path.as_ref().to_string_lossy().to_string()
+}
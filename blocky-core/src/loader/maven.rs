@@ -0,0 +1,90 @@
+use crate::cache;
+use crate::minecraft::download_options::DownloadOptions;
+use crate::minecraft::error::MinecraftError;
+use crate::minecraft::models::maven_coordinate::MavenCoordinate;
+use crate::utils::{download_files, DownloadTask};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+
+// Versions listed in `maven-metadata.xml` of an artifact
+pub fn metadata_versions(
+    repository_url: &str,
+    group: &str,
+    artifact: &str,
+    download_options: &DownloadOptions,
+) -> Result<Vec<String>, MinecraftError> {
+    let url = format!(
+        "{}/{}/{}/maven-metadata.xml",
+        repository_url.trim_end_matches('/'),
+        group.replace('.', "/"),
+        artifact
+    );
+    let response = cache::get(&url, download_options).map_err(MinecraftError::Download)?;
+
+    Ok(parse_versions(&String::from_utf8_lossy(&response)))
+}
+
+// Downloads an artifact into the libraries, verified by the `.sha1` file next to it in the
+// repository. An existing file is only reused if it matches.
+pub fn download_artifact(
+    repository_url: &str,
+    coordinate: &MavenCoordinate,
+    libraries_path: impl AsRef<Path>,
+    download_options: &DownloadOptions,
+) -> Result<PathBuf, MinecraftError> {
+    let artifact_path = libraries_path.as_ref().join(coordinate.path());
+    let url = format!(
+        "{}/{}",
+        repository_url.trim_end_matches('/'),
+        coordinate.url_path()
+    );
+    let sha1 = artifact_sha1(&url, download_options)?;
+
+    let task = DownloadTask {
+        url,
+        dest: artifact_path.clone(),
+        sha1: Some(sha1),
+        size: None,
+    };
+    download_files(&[task], download_options, &AtomicBool::new(false), |_| {})
+        .map_err(MinecraftError::Download)?;
+
+    Ok(artifact_path)
+}
+
+// Checksum files contain the hex digest, sometimes followed by the file name
+fn artifact_sha1(
+    artifact_url: &str,
+    download_options: &DownloadOptions,
+) -> Result<Vec<u8>, MinecraftError> {
+    let url = format!("{}.sha1", artifact_url);
+    let response = cache::get(&url, download_options).map_err(MinecraftError::Download)?;
+    let checksum = String::from_utf8_lossy(&response);
+    let digest = checksum.split_whitespace().next().unwrap_or_default();
+
+    match hex::decode(digest) {
+        Ok(sha1) if sha1.len() == 20 => Ok(sha1),
+        _ => Err(MinecraftError::InvalidChecksum(url)),
+    }
+}
+
+// Compares the numeric components, e.g. `43.1.10` is newer than `43.1.9`
+pub fn sort_newest_first(versions: &mut [String]) {
+    versions.sort_by_key(|version| {
+        std::cmp::Reverse(
+            version
+                .split(|c: char| !c.is_ascii_digit())
+                .filter_map(|part| part.parse::<u64>().ok())
+                .collect::<Vec<_>>(),
+        )
+    });
+}
+
+fn parse_versions(metadata: &str) -> Vec<String> {
+    metadata
+        .split("<version>")
+        .skip(1)
+        .filter_map(|part| part.split_once("</version>"))
+        .map(|(version, _)| version.trim().to_string())
+        .collect()
+}
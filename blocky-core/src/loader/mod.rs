@@ -0,0 +1,7 @@
+pub mod fabric;
+pub mod forge;
+pub mod installer;
+pub mod maven;
+pub mod models;
+pub mod neoforge;
+pub mod quilt;
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+// Entry of `/v2/versions/loader/<game_version>`
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FabricLoaderVersion {
+    pub loader: FabricComponent,
+    pub intermediary: FabricComponent,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FabricComponent {
+    pub maven: String,
+    pub version: String,
+    #[serde(default)]
+    pub stable: bool,
+}
@@ -0,0 +1,46 @@
+use crate::minecraft::models::library::Library;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// `install_profile.json` of Forge installers for 1.13+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct InstallProfile {
+    pub spec: u32,
+    pub version: String,
+    pub minecraft: String,
+    // Location of the version data inside the installer, e.g. `/version.json`
+    pub json: String,
+    #[serde(default)]
+    pub data: HashMap<String, DataEntry>,
+    #[serde(default)]
+    pub processors: Vec<Processor>,
+    #[serde(default)]
+    pub libraries: Vec<Library>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DataEntry {
+    pub client: String,
+    pub server: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Processor {
+    // Runs on both sides if empty
+    #[serde(default)]
+    pub sides: Vec<String>,
+    pub jar: String,
+    #[serde(default)]
+    pub classpath: Vec<String>,
+    #[serde(default)]
+    pub args: Vec<String>,
+    // Expected SHA1 by file
+    #[serde(default)]
+    pub outputs: HashMap<String, String>,
+}
+
+impl Processor {
+    pub fn runs_on_client(&self) -> bool {
+        self.sides.is_empty() || self.sides.iter().any(|side| side == "client")
+    }
+}
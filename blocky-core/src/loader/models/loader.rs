@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+// Mod loader installed on top of the game version of an instance
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Loader {
+    pub kind: LoaderKind,
+    pub version: String,
+}
+
+impl Loader {
+    // ID of the version data written for the loader
+    pub fn version_id(&self, game_version: &str) -> String {
+        format!("{}-loader-{}-{}", self.kind, &self.version, game_version)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum LoaderKind {
+    #[serde(alias = "fabric")]
+    Fabric,
+    #[serde(alias = "forge")]
+    Forge,
+    #[serde(alias = "quilt")]
+    Quilt,
+    #[serde(alias = "neoforge")]
+    NeoForge,
+}
+
+impl LoaderKind {
+    pub fn all() -> Vec<Self> {
+        vec![Self::Fabric, Self::Forge, Self::Quilt, Self::NeoForge]
+    }
+
+    // Loaders which patch the game by running an installer
+    pub fn uses_installer(&self) -> bool {
+        matches!(self, LoaderKind::Forge | LoaderKind::NeoForge)
+    }
+}
+
+impl Display for LoaderKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoaderKind::Fabric => write!(f, "fabric"),
+            LoaderKind::Forge => write!(f, "forge"),
+            LoaderKind::Quilt => write!(f, "quilt"),
+            LoaderKind::NeoForge => write!(f, "neoforge"),
+        }
+    }
+}
+
+impl FromStr for LoaderKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fabric" => Ok(Self::Fabric),
+            "forge" => Ok(Self::Forge),
+            "quilt" => Ok(Self::Quilt),
+            "neoforge" => Ok(Self::NeoForge),
+            _ => Err(format!("Unknown mod loader '{}'", s)),
+        }
+    }
+}
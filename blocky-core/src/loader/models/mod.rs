@@ -0,0 +1,4 @@
+pub mod fabric_loader_version;
+pub mod install_profile;
+pub mod loader;
+pub mod quilt_loader_version;
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+// Entry of `/v3/versions/loader/<game_version>`
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct QuiltLoaderVersion {
+    pub loader: QuiltComponent,
+    pub intermediary: QuiltComponent,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct QuiltComponent {
+    pub maven: String,
+    pub version: String,
+}
@@ -0,0 +1,87 @@
+use crate::endpoints::Endpoints;
+use crate::loader::maven;
+use crate::minecraft::download_options::DownloadOptions;
+use crate::minecraft::error::MinecraftError;
+use crate::minecraft::models::maven_coordinate::MavenCoordinate;
+use std::path::{Path, PathBuf};
+
+const NEOFORGE_GROUP: &str = "net.neoforged";
+const NEOFORGE_ARTIFACT: &str = "neoforge";
+// NeoForge for 1.20.1 was published as a fork of Forge
+const LEGACY_GAME_VERSION: &str = "1.20.1";
+const LEGACY_ARTIFACT: &str = "forge";
+
+// NeoForge versions which support `game_version`, newest first
+pub fn loader_versions(
+    game_version: &str,
+    endpoints: &Endpoints,
+    download_options: &DownloadOptions,
+) -> Result<Vec<String>, MinecraftError> {
+    debug!("Getting NeoForge versions");
+    trace!("Game Version: {}", game_version);
+
+    let (artifact, prefix) = match game_version {
+        LEGACY_GAME_VERSION => (LEGACY_ARTIFACT, format!("{}-", game_version)),
+        _ => (NEOFORGE_ARTIFACT, String::new()),
+    };
+    let mut versions = maven::metadata_versions(
+        &endpoints.neoforge_maven_url,
+        NEOFORGE_GROUP,
+        artifact,
+        download_options,
+    )?
+    .into_iter()
+    .filter_map(|version| version.strip_prefix(&prefix).map(String::from))
+    .filter(|version| artifact == LEGACY_ARTIFACT || supports(version, game_version))
+    .collect::<Vec<_>>();
+    maven::sort_newest_first(&mut versions);
+
+    Ok(versions)
+}
+
+// Downloads the installer into the libraries, e.g.
+// `net/neoforged/neoforge/20.4.80-beta/neoforge-20.4.80-beta-installer.jar`
+pub fn download_installer(
+    game_version: &str,
+    loader_version: &str,
+    libraries_path: impl AsRef<Path>,
+    endpoints: &Endpoints,
+    download_options: &DownloadOptions,
+) -> Result<PathBuf, MinecraftError> {
+    debug!("Downloading NeoForge installer");
+    trace!("Game Version: {}", game_version);
+    trace!("Loader Version: {}", loader_version);
+
+    let (artifact, version) = match game_version {
+        LEGACY_GAME_VERSION => (
+            LEGACY_ARTIFACT,
+            format!("{}-{}", game_version, loader_version),
+        ),
+        _ => (NEOFORGE_ARTIFACT, loader_version.to_string()),
+    };
+    let coordinate = MavenCoordinate {
+        group: NEOFORGE_GROUP.to_string(),
+        artifact: artifact.to_string(),
+        version,
+        classifier: Some("installer".to_string()),
+        extension: String::from("jar"),
+    };
+
+    maven::download_artifact(
+        &endpoints.neoforge_maven_url,
+        &coordinate,
+        libraries_path,
+        download_options,
+    )
+}
+
+// Versions start with the minor and patch version of the game, e.g. `20.4.80-beta` for `1.20.4`
+fn supports(loader_version: &str, game_version: &str) -> bool {
+    let mut parts = game_version.split('.').skip(1);
+    let (minor, patch) = match (parts.next(), parts.next()) {
+        (Some(minor), patch) => (minor, patch.unwrap_or("0")),
+        _ => return false,
+    };
+
+    loader_version.starts_with(&format!("{}.{}.", minor, patch))
+}
@@ -0,0 +1,47 @@
+use crate::cache;
+use crate::endpoints::Endpoints;
+use crate::loader::models::quilt_loader_version::QuiltLoaderVersion;
+use crate::minecraft::download_options::DownloadOptions;
+use crate::minecraft::error::MinecraftError;
+
+// Loader versions which support `game_version`, newest first
+pub fn loader_versions(
+    game_version: &str,
+    endpoints: &Endpoints,
+    download_options: &DownloadOptions,
+) -> Result<Vec<QuiltLoaderVersion>, MinecraftError> {
+    debug!("Getting Quilt loader versions");
+    trace!("Game Version: {}", game_version);
+
+    let url = format!(
+        "{}/v3/versions/loader/{}",
+        endpoints.quilt_meta_url.trim_end_matches('/'),
+        game_version
+    );
+    let response = cache::get(&url, download_options).map_err(MinecraftError::Download)?;
+    let versions = serde_json::from_slice::<Vec<QuiltLoaderVersion>>(&response)
+        .map_err(MinecraftError::Serde)?;
+
+    Ok(versions)
+}
+
+// Version data inheriting from `game_version`
+pub fn profile(
+    game_version: &str,
+    loader_version: &str,
+    endpoints: &Endpoints,
+    download_options: &DownloadOptions,
+) -> Result<Vec<u8>, MinecraftError> {
+    debug!("Getting Quilt profile");
+    trace!("Game Version: {}", game_version);
+    trace!("Loader Version: {}", loader_version);
+
+    let url = format!(
+        "{}/v3/versions/loader/{}/{}/profile/json",
+        endpoints.quilt_meta_url.trim_end_matches('/'),
+        game_version,
+        loader_version
+    );
+
+    cache::get(&url, download_options).map_err(MinecraftError::Download)
+}
@@ -1,5 +1,7 @@
+use crate::minecraft::launch_features::QuickPlay;
 use crate::minecraft::launch_options::LaunchOptions;
 use crate::minecraft::models::version_data::VersionData;
+use crate::os::Platform;
 use std::collections::HashMap;
 
 pub struct ArgumentReplacements {
@@ -7,30 +9,35 @@ pub struct ArgumentReplacements {
 }
 
 impl ArgumentReplacements {
+    #[allow(clippy::too_many_arguments)]
     pub fn build(
         options: &LaunchOptions,
         version_data: &VersionData,
         classpath: String,
         minecraft_path: String,
         assets_path: String,
+        game_assets_path: String,
         natives_path: String,
+        libraries_path: String,
     ) -> Self {
         let mut result = HashMap::new();
         result.insert(
             "${auth_player_name}".to_string(),
             options.player_name.clone(),
         );
-        result.insert("${game_directory}".to_string(), minecraft_path.clone());
+        result.insert("${game_directory}".to_string(), minecraft_path);
         result.insert(
             "${assets_index_name}".to_string(),
             version_data.assets.clone(),
         );
         result.insert("${assets_root}".to_string(), assets_path);
+        result.insert("${game_assets}".to_string(), game_assets_path);
+        result.insert("${natives_directory}".to_string(), natives_path);
+        result.insert("${library_directory}".to_string(), libraries_path);
         result.insert(
-            "${game_assets}".to_string(),
-            format!("{}/assets", minecraft_path),
+            "${classpath_separator}".to_string(),
+            Platform::current().classpath_seperator().to_string(),
         );
-        result.insert("${natives_directory}".to_string(), natives_path);
         result.insert("${version_name}".to_string(), version_data.id.clone());
         result.insert(
             "${version_type}".to_string(),
@@ -45,6 +52,33 @@ impl ArgumentReplacements {
             options.launcher_version.clone(),
         );
         result.insert("${classpath}".to_string(), classpath);
+        result.insert(
+            "${resolution_width}".to_string(),
+            options.window_width.to_string(),
+        );
+        result.insert(
+            "${resolution_height}".to_string(),
+            options.window_height.to_string(),
+        );
+
+        if let Some(quick_play_path) = &options.quick_play_path {
+            result.insert(
+                "${quickPlayPath}".to_string(),
+                quick_play_path.to_string_lossy().to_string(),
+            );
+        }
+        match &options.quick_play {
+            Some(QuickPlay::Singleplayer(world)) => {
+                result.insert("${quickPlaySingleplayer}".to_string(), world.clone());
+            }
+            Some(QuickPlay::Multiplayer(server)) => {
+                result.insert("${quickPlayMultiplayer}".to_string(), server.clone());
+            }
+            Some(QuickPlay::Realms(realm)) => {
+                result.insert("${quickPlayRealms}".to_string(), realm.clone());
+            }
+            None => {}
+        }
 
         if let (Some(profile_id), Some(token)) = (&options.profile_id, &options.token) {
             result.insert("${auth_uuid}".to_string(), profile_id.clone());
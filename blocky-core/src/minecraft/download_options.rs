@@ -0,0 +1,44 @@
+use derive_builder::Builder;
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[derive(Builder, Clone, Debug)]
+pub struct DownloadOptions {
+    #[builder(default = "8")]
+    pub workers: usize,
+    // Retry policy
+    #[builder(default = "3")]
+    pub max_attempts: u32,
+    #[builder(default = "Duration::from_millis(500)")]
+    pub retry_delay: Duration,
+    // Shared content-addressed store
+    #[builder(default)]
+    #[builder(setter(strip_option))]
+    pub store_path: Option<PathBuf>,
+    // Managed Java runtimes are installed along with instances if set
+    #[builder(default)]
+    #[builder(setter(strip_option))]
+    pub java_runtimes_path: Option<PathBuf>,
+    // Java for loader installers if neither the instance nor a managed runtime provides one
+    #[builder(default)]
+    pub java_exec: String,
+    // Cache for version metadata
+    #[builder(default)]
+    #[builder(setter(strip_option))]
+    pub cache_path: Option<PathBuf>,
+    #[builder(default = "Duration::from_secs(10 * 60)")]
+    pub cache_max_age: Duration,
+}
+
+impl Default for DownloadOptions {
+    fn default() -> Self {
+        DownloadOptionsBuilder::default().build().unwrap()
+    }
+}
+
+impl DownloadOptions {
+    // Exponential backoff, doubling the delay after every failed attempt
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        self.retry_delay * 2u32.saturating_pow(attempt.saturating_sub(1))
+    }
+}
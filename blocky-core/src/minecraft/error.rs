@@ -2,8 +2,8 @@ use crate::error::DownloadError;
 
 #[derive(Debug, thiserror::Error)]
 pub enum MinecraftError {
-    #[error("The provided name for a library is invalid")]
-    LibraryNameFormat,
+    #[error("The provided name for a library is invalid: {0}")]
+    LibraryNameFormat(String),
 
     #[error("Failed to fork new process")]
     Forking,
@@ -17,6 +17,42 @@ pub enum MinecraftError {
     #[error("{0}")]
     Download(DownloadError),
 
+    #[error("{0}")]
+    Serde(serde_json::Error),
+
     #[error("{0}")]
     Extract(zip::result::ZipError),
+
+    #[error("No Java runtime '{0}' is available for this platform")]
+    JavaRuntimeUnavailable(String),
+
+    #[error("Java executable '{0}' does not exist")]
+    JavaNotFound(String),
+
+    #[error("Could not determine the version of Java executable '{0}'")]
+    UnknownJavaVersion(String),
+
+    #[error("'{0}' is Java {1}, but this version of Minecraft requires Java {2} or newer")]
+    JavaVersionMismatch(String, u32, u32),
+
+    #[error("'{0}' is a 32-bit Java, which can not load the natives for this system")]
+    Java32Bit(String),
+
+    #[error("Inherited version '{0}' is not available")]
+    InheritedVersion(String),
+
+    #[error("Version '{0}' is part of an inheritance cycle")]
+    InheritanceCycle(String),
+
+    #[error("Checksum file '{0}' is invalid")]
+    InvalidChecksum(String),
+
+    #[error("Invalid mod loader installer: {0}")]
+    LoaderInstaller(String),
+
+    #[error("Processor '{0}' failed: {1}")]
+    ProcessorFailed(String, String),
+
+    #[error("Processor output '{0}' does not match its checksum")]
+    ProcessorOutput(String),
 }
@@ -0,0 +1,273 @@
+use crate::minecraft::error::MinecraftError;
+use crate::minecraft::models::maven_coordinate::MavenCoordinate;
+use crate::minecraft::models::version_data::VersionData;
+use serde_json::Value;
+use std::collections::HashSet;
+
+// Version data of mod loaders and custom versions only contains what they add to the version
+// they inherit from. The chain is merged from the root down, so children win on conflicts.
+pub fn resolve_version_data(
+    version_data: Value,
+    mut load_parent: impl FnMut(&str) -> Result<Value, MinecraftError>,
+) -> Result<VersionData, MinecraftError> {
+    let mut chain = vec![version_data];
+    let mut seen = HashSet::new();
+
+    while let Some(parent_id) = inherits_from(chain.last().unwrap()) {
+        trace!("Resolving inherited version: {}", &parent_id);
+        if !seen.insert(parent_id.clone()) {
+            return Err(MinecraftError::InheritanceCycle(parent_id));
+        }
+
+        chain.push(load_parent(&parent_id)?);
+    }
+
+    let mut resolved = chain.pop().unwrap();
+    while let Some(child) = chain.pop() {
+        resolved = merge(resolved, child);
+    }
+
+    if let Value::Object(resolved) = &mut resolved {
+        resolved.remove("inheritsFrom");
+    }
+
+    serde_json::from_value(resolved).map_err(MinecraftError::Serde)
+}
+
+fn inherits_from(version_data: &Value) -> Option<String> {
+    version_data
+        .get("inheritsFrom")
+        .and_then(Value::as_str)
+        .map(String::from)
+}
+
+fn merge(parent: Value, child: Value) -> Value {
+    let (mut parent, child) = match (parent, child) {
+        (Value::Object(parent), Value::Object(child)) => (parent, child),
+        (_, child) => return child,
+    };
+
+    // The client jar stays the one of the version at the root of the chain
+    if !child.contains_key("jar") {
+        if let Some(jar) = parent.get("jar").or_else(|| parent.get("id")).cloned() {
+            parent.insert("jar".to_string(), jar);
+        }
+    }
+
+    for (key, value) in child {
+        let merged = match (key.as_str(), parent.remove(&key)) {
+            ("inheritsFrom", _) => continue,
+            ("libraries", Some(parent_libraries)) => merge_libraries(parent_libraries, value),
+            ("arguments", Some(parent_arguments)) => merge_arguments(parent_arguments, value),
+            (_, _) => value,
+        };
+        parent.insert(key, merged);
+    }
+
+    Value::Object(parent)
+}
+
+// Libraries of the child come first and replace the ones of the parent with the same group,
+// artifact and classifier
+fn merge_libraries(parent: Value, child: Value) -> Value {
+    let (parent, mut child) = match (parent, child) {
+        (Value::Array(parent), Value::Array(child)) => (parent, child),
+        (_, child) => return child,
+    };
+
+    let child_keys = child.iter().filter_map(library_key).collect::<HashSet<_>>();
+    child.extend(
+        parent
+            .into_iter()
+            .filter(|library| match library_key(library) {
+                Some(key) => !child_keys.contains(&key),
+                None => true,
+            }),
+    );
+
+    Value::Array(child)
+}
+
+fn library_key(library: &Value) -> Option<(String, String, Option<String>)> {
+    let coordinate = library
+        .get("name")?
+        .as_str()?
+        .parse::<MavenCoordinate>()
+        .ok()?;
+
+    Some((coordinate.group, coordinate.artifact, coordinate.classifier))
+}
+
+// Arguments of the child are appended to the ones of the parent
+fn merge_arguments(parent: Value, child: Value) -> Value {
+    let (mut parent, child) = match (parent, child) {
+        (Value::Object(parent), Value::Object(child)) => (parent, child),
+        (_, child) => return child,
+    };
+
+    for (key, value) in child {
+        let merged = match (parent.remove(&key), value) {
+            (Some(Value::Array(mut parent_values)), Value::Array(child_values)) => {
+                parent_values.extend(child_values);
+                Value::Array(parent_values)
+            }
+            (_, value) => value,
+        };
+        parent.insert(key, merged);
+    }
+
+    Value::Object(parent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn vanilla() -> Value {
+        json!({
+            "id": "1.19.2",
+            "assets": "1.19",
+            "complianceLevel": 1,
+            "mainClass": "net.minecraft.client.main.Main",
+            "minimumLauncherVersion": 21,
+            "releaseTime": "2022-08-05T11:57:05+00:00",
+            "time": "2022-08-05T11:57:05+00:00",
+            "type": "release",
+            "libraries": [
+                { "name": "org.ow2.asm:asm:9.1" },
+                { "name": "org.lwjgl:lwjgl:3.3.1" },
+            ],
+            "arguments": {
+                "game": ["--username", "${auth_player_name}"],
+                "jvm": ["-Djava.library.path=${natives_directory}"],
+            },
+        })
+    }
+
+    fn fabric() -> Value {
+        json!({
+            "id": "fabric-loader-0.14.9-1.19.2",
+            "inheritsFrom": "1.19.2",
+            "mainClass": "net.fabricmc.loader.impl.launch.knot.KnotClient",
+            "libraries": [
+                { "name": "org.ow2.asm:asm:9.3" },
+                { "name": "net.fabricmc:fabric-loader:0.14.9" },
+            ],
+            "arguments": {
+                "game": [],
+                "jvm": ["-DFabricMcEmu= net.minecraft.client.main.Main "],
+            },
+        })
+    }
+
+    fn load(id: &str) -> Result<Value, MinecraftError> {
+        match id {
+            "1.19.2" => Ok(vanilla()),
+            _ => Err(MinecraftError::InheritedVersion(id.to_string())),
+        }
+    }
+
+    #[test]
+    fn child_wins_on_conflicts() {
+        let version_data = resolve_version_data(fabric(), load).unwrap();
+
+        assert_eq!(version_data.id, "fabric-loader-0.14.9-1.19.2");
+        assert_eq!(
+            version_data.main_class,
+            "net.fabricmc.loader.impl.launch.knot.KnotClient"
+        );
+        assert_eq!(version_data.assets, "1.19");
+        assert_eq!(version_data.client_jar_id(), "1.19.2");
+        assert_eq!(version_data.inherits_from, None);
+
+        let libraries = version_data
+            .libraries
+            .iter()
+            .map(|library| library.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            libraries,
+            vec![
+                "org.ow2.asm:asm:9.3",
+                "net.fabricmc:fabric-loader:0.14.9",
+                "org.lwjgl:lwjgl:3.3.1",
+            ]
+        );
+    }
+
+    #[test]
+    fn arguments_are_appended() {
+        let merged = merge(vanilla(), fabric());
+
+        assert_eq!(
+            merged["arguments"],
+            json!({
+                "game": ["--username", "${auth_player_name}"],
+                "jvm": [
+                    "-Djava.library.path=${natives_directory}",
+                    "-DFabricMcEmu= net.minecraft.client.main.Main ",
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn legacy_arguments() {
+        let mut parent = vanilla();
+        parent.as_object_mut().unwrap().remove("arguments");
+        parent["minecraftArguments"] = json!("--username ${auth_player_name}");
+
+        let child = json!({ "id": "forge", "inheritsFrom": "1.19.2" });
+        let merged = merge(parent.clone(), child);
+        assert_eq!(
+            merged["minecraftArguments"],
+            json!("--username ${auth_player_name}")
+        );
+
+        // Legacy arguments are complete, so the child replaces them
+        let child = json!({
+            "id": "forge",
+            "inheritsFrom": "1.19.2",
+            "minecraftArguments": "--username ${auth_player_name} --tweakClass Tweaker",
+        });
+        let merged = merge(parent, child);
+        assert_eq!(
+            merged["minecraftArguments"],
+            json!("--username ${auth_player_name} --tweakClass Tweaker")
+        );
+    }
+
+    #[test]
+    fn child_jar_is_kept() {
+        let mut child = fabric();
+        child["jar"] = json!("1.19.1");
+
+        let merged = merge(vanilla(), child);
+        assert_eq!(merged["jar"], json!("1.19.1"));
+    }
+
+    #[test]
+    fn missing_parent() {
+        let mut child = fabric();
+        child["inheritsFrom"] = json!("1.18.2");
+
+        let result = resolve_version_data(child, load);
+        assert!(matches!(
+            result,
+            Err(MinecraftError::InheritedVersion(id)) if id == "1.18.2"
+        ));
+    }
+
+    #[test]
+    fn inheritance_cycle() {
+        let first = json!({ "id": "first", "inheritsFrom": "second" });
+        let result = resolve_version_data(first, |id| match id {
+            "first" => Ok(json!({ "id": "first", "inheritsFrom": "second" })),
+            "second" => Ok(json!({ "id": "second", "inheritsFrom": "first" })),
+            _ => Err(MinecraftError::InheritedVersion(id.to_string())),
+        });
+
+        assert!(matches!(result, Err(MinecraftError::InheritanceCycle(_))));
+    }
+}
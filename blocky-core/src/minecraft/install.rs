@@ -1,11 +1,14 @@
-use crate::consts;
+use crate::endpoints::Endpoints;
+use crate::minecraft::download_options::DownloadOptions;
 use crate::minecraft::error::MinecraftError;
 use crate::minecraft::installation_update::{InstallationUpdate, Progress};
 use crate::minecraft::models::asset_index_data::AssetIndexData;
 use crate::minecraft::models::version_data::VersionData;
+use crate::minecraft::utils;
 use crate::minecraft::utils::extract_native;
-use crate::utils::download_file_check;
+use crate::utils::{download_files, link_or_copy, DownloadTask};
 use crossbeam_channel::Sender;
+use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -15,6 +18,8 @@ pub fn install_libraries(
     version_data: &VersionData,
     libraries_path: impl AsRef<Path>,
     natives_path: impl AsRef<Path>,
+    endpoints: &Endpoints,
+    download_options: &DownloadOptions,
     update_sender: Sender<InstallationUpdate>,
     cancel: Arc<AtomicBool>,
 ) -> Result<(), MinecraftError> {
@@ -26,219 +31,88 @@ pub fn install_libraries(
     );
     trace!("Natives Path: {}", natives_path.as_ref().to_string_lossy());
 
-    let needed_libraries = version_data.needed_libraries();
-    let mut update_progress_template = Progress {
-        total_files: needed_libraries.len(),
-        ..Default::default()
-    };
-
-    for (n, library) in needed_libraries.into_iter().enumerate() {
-        trace!("Library: {}", &library.name);
-        let artifact = &library.downloads.artifact;
-
-        // Check if canceled
-        if cancel.load(Ordering::Relaxed) {
-            trace!("Cancel installation");
-            let _ = update_sender.send(InstallationUpdate::Cancel);
-            return Ok(());
-        }
-
-        // Send update
-        update_progress_template.current_file = n + 1;
-        update_progress_template.current_file_url = artifact.url.clone();
-        update_progress_template.current_file_size = Some(artifact.size);
-        let _ = update_sender.send(InstallationUpdate::Library(
-            update_progress_template.clone(),
-        ));
-
-        // Extract library information
-        let (mut package, name, version) = library.extract_information()?;
-        package = package.replace('.', "/");
-
-        let mut library_path = PathBuf::from(libraries_path.as_ref());
-        library_path.push(&package);
-        library_path.push(&name);
-        library_path.push(&version);
-
-        // Create path
-        fs::create_dir_all(&library_path).map_err(MinecraftError::IO)?;
-
-        // Download library file
-        let jar_name = format!("{}-{}.jar", name, version);
-        let mut jar_path = library_path.clone();
-        jar_path.push(&jar_name);
-
-        let jar_sha = hex::decode(&artifact.sha1).map_err(MinecraftError::Sha1Decode)?;
-        download_file_check(&artifact.url, &jar_path, Some(jar_sha))
-            .map_err(MinecraftError::Download)?;
-
-        // Native
-        if let Some(native) = library.get_native() {
-            trace!("Native: {}", &library.name);
-            let native_jar_name = format!("{}-{}-{}.jar", name, version, native);
-            let mut native_jar_path = library_path.clone();
-            native_jar_path.push(&native_jar_name);
-
-            let native_download_url = format!(
-                "{}/{}/{}/{}/{}",
-                consts::MC_LIBRARIES_BASE_URL,
-                &package,
-                &name,
-                &version,
-                &native_jar_name
-            );
-
-            // Send update
-            update_progress_template.current_file_url = native_download_url.clone();
-            update_progress_template.current_file_size = None;
-            let _ = update_sender.send(InstallationUpdate::Library(
-                update_progress_template.clone(),
-            ));
-
-            // TODO: Check SHA1
-            download_file_check(&native_download_url, &native_jar_path, None)
-                .map_err(MinecraftError::Download)?;
-
-            // Extract
-            if let Some(extract) = &library.extract {
-                extract_native(&native_jar_path, &natives_path, &extract.exclude)?;
-            }
-        }
+    let (tasks, natives) = library_tasks(version_data, libraries_path, endpoints)?;
+
+    // Download all files
+    if !download_all(
+        &tasks,
+        download_options,
+        InstallationUpdate::Library,
+        &update_sender,
+        &cancel,
+    )? {
+        return Ok(());
     }
 
+    extract_natives(&natives, natives_path)?;
+
     Ok(())
 }
 
 pub fn install_assets(
     asset_index: &AssetIndexData,
     assets_path: impl AsRef<Path>,
+    endpoints: &Endpoints,
+    download_options: &DownloadOptions,
     update_sender: Sender<InstallationUpdate>,
     cancel: Arc<AtomicBool>,
 ) -> Result<(), MinecraftError> {
     debug!("Install assets");
     trace!("Assets Path: {}", assets_path.as_ref().to_string_lossy());
 
-    let mut objects_path = PathBuf::from(assets_path.as_ref());
-    objects_path.push("objects");
+    let tasks = asset_tasks(asset_index, assets_path, endpoints)?;
 
-    let mut update_progress_template = Progress {
-        total_files: asset_index.objects.len(),
-        ..Default::default()
-    };
-
-    for (n, asset_info) in asset_index.objects.values().enumerate() {
-        trace!("Asset: {}", &asset_info.hash);
-        let hash_part: String = asset_info.hash.chars().take(2).collect();
-        let asset_url = format!(
-            "{}/{}/{}",
-            consts::MC_ASSETS_BASE_URL,
-            &hash_part,
-            &asset_info.hash
-        );
-
-        // Check if canceled
-        if cancel.load(Ordering::Relaxed) {
-            trace!("Cancel installation");
-            let _ = update_sender.send(InstallationUpdate::Cancel);
-            return Ok(());
-        }
-
-        // Send update
-        update_progress_template.current_file = n + 1;
-        update_progress_template.current_file_url = asset_url.clone();
-        update_progress_template.current_file_size = Some(asset_info.size);
-        let _ = update_sender.send(InstallationUpdate::Asset(update_progress_template.clone()));
-
-        let mut asset_path = objects_path.clone();
-        asset_path.push(&hash_part);
-
-        // Create path
-        fs::create_dir_all(&asset_path).map_err(MinecraftError::IO)?;
-
-        asset_path.push(&asset_info.hash);
-
-        let asset_sha = hex::decode(&asset_info.hash).map_err(MinecraftError::Sha1Decode)?;
-        download_file_check(&asset_url, &asset_path, Some(asset_sha))
-            .map_err(MinecraftError::Download)?;
-    }
+    download_all(
+        &tasks,
+        download_options,
+        InstallationUpdate::Asset,
+        &update_sender,
+        &cancel,
+    )?;
 
     Ok(())
 }
 
+// Lays out already downloaded assets by their name as expected by legacy versions
 pub fn install_resources(
     asset_index: &AssetIndexData,
+    assets_path: impl AsRef<Path>,
     resources_path: impl AsRef<Path>,
-    minecraft_path: impl AsRef<Path>,
-    update_sender: Sender<InstallationUpdate>,
-    cancel: Arc<AtomicBool>,
 ) -> Result<(), MinecraftError> {
     debug!("Install resources");
+    trace!("Assets Path: {}", assets_path.as_ref().to_string_lossy());
     trace!(
         "Resources Path: {}",
         resources_path.as_ref().to_string_lossy()
     );
-    trace!(
-        "Minecraft Path: {}",
-        minecraft_path.as_ref().to_string_lossy()
-    );
-
-    let mut update_progress_template = Progress {
-        total_files: asset_index.objects.len(),
-        ..Default::default()
-    };
-
-    for (n, (key, asset_info)) in asset_index.objects.iter().enumerate() {
-        trace!("Resource: {}", key);
-        let hash_part: String = asset_info.hash.chars().take(2).collect();
-        let asset_url = format!(
-            "{}/{}/{}",
-            consts::MC_ASSETS_BASE_URL,
-            &hash_part,
-            &asset_info.hash
-        );
-
-        // Check if canceled
-        if cancel.load(Ordering::Relaxed) {
-            trace!("Cancel installation");
-            let _ = update_sender.send(InstallationUpdate::Cancel);
-            return Ok(());
-        }
-
-        // Send update
-        update_progress_template.current_file = n + 1;
-        update_progress_template.current_file_url = asset_url.clone();
-        update_progress_template.current_file_size = Some(asset_info.size);
-        let _ = update_sender.send(InstallationUpdate::Asset(update_progress_template.clone()));
 
-        // Create path
+    for (key, asset_info) in &asset_index.objects {
         let mut resource_path = PathBuf::from(resources_path.as_ref());
         resource_path.push(key);
-        resource_path.pop();
-        fs::create_dir_all(&resource_path).map_err(MinecraftError::IO)?;
 
-        let mut resource_path = PathBuf::from(resources_path.as_ref());
-        resource_path.push(key);
+        let is_present = fs::metadata(&resource_path)
+            .map(|metadata| metadata.len() == asset_info.size as u64)
+            .unwrap_or(false);
+        if is_present {
+            continue;
+        }
 
-        let resource_sha = hex::decode(&asset_info.hash).map_err(MinecraftError::Sha1Decode)?;
-        download_file_check(&asset_url, &resource_path, Some(resource_sha))
+        trace!("Resource: {}", key);
+        if let Some(parent) = resource_path.parent() {
+            fs::create_dir_all(parent).map_err(MinecraftError::IO)?;
+        }
+        link_or_copy(object_path(&assets_path, &asset_info.hash), &resource_path)
             .map_err(MinecraftError::Download)?;
     }
 
-    // Create symlink
-    let mut minecraft_resources_path = PathBuf::from(minecraft_path.as_ref());
-    minecraft_resources_path.push("resources");
-    if !minecraft_resources_path.exists() {
-        trace!("Creating symlink for resources");
-        symlink::symlink_dir(&resources_path, minecraft_resources_path)
-            .map_err(MinecraftError::IO)?;
-    }
-
     Ok(())
 }
 
 pub fn install_log_config(
     version_data: &VersionData,
     log_configs_path: impl AsRef<Path>,
+    endpoints: &Endpoints,
+    download_options: &DownloadOptions,
     update_sender: Sender<InstallationUpdate>,
     cancel: Arc<AtomicBool>,
 ) -> Result<(), MinecraftError> {
@@ -249,7 +123,7 @@ pub fn install_log_config(
         log_configs_path.as_ref().to_string_lossy()
     );
 
-    if let Some(logging_info) = &version_data.logging {
+    if let Some(task) = log_config_task(version_data, log_configs_path, endpoints)? {
         // Check if canceled
         if cancel.load(Ordering::Relaxed) {
             trace!("Cancel installation");
@@ -257,36 +131,13 @@ pub fn install_log_config(
             return Ok(());
         }
 
-        // Send update
-        let update_progress = Progress {
-            total_files: 1,
-            current_file: 1,
-            current_file_url: logging_info.client.file.url.clone(),
-            current_file_size: Some(logging_info.client.file.size),
-        };
-        let _ = update_sender.send(InstallationUpdate::LogConfig(update_progress));
-
-        // Create path
-        fs::create_dir_all(&log_configs_path).map_err(MinecraftError::IO)?;
-
-        let mut config_path = PathBuf::from(log_configs_path.as_ref());
-        config_path.push(
-            &logging_info
-                .client
-                .file
-                .id
-                .as_ref()
-                .expect("Logging info has no ID"),
-        );
-
-        let config_sha =
-            hex::decode(&logging_info.client.file.sha1).map_err(MinecraftError::Sha1Decode)?;
-        download_file_check(
-            &logging_info.client.file.url,
-            &config_path,
-            Some(config_sha),
-        )
-        .map_err(MinecraftError::Download)?;
+        download_all(
+            &[task],
+            download_options,
+            InstallationUpdate::LogConfig,
+            &update_sender,
+            &cancel,
+        )?;
     }
 
     Ok(())
@@ -295,6 +146,8 @@ pub fn install_log_config(
 pub fn install_client(
     version_data: &VersionData,
     minecraft_path: impl AsRef<Path>,
+    endpoints: &Endpoints,
+    download_options: &DownloadOptions,
     update_sender: Sender<InstallationUpdate>,
     cancel: Arc<AtomicBool>,
 ) -> Result<(), MinecraftError> {
@@ -305,7 +158,7 @@ pub fn install_client(
         minecraft_path.as_ref().to_string_lossy()
     );
 
-    if let Some(downloads) = &version_data.downloads {
+    if let Some(task) = client_task(version_data, minecraft_path, endpoints)? {
         // Check if canceled
         if cancel.load(Ordering::Relaxed) {
             trace!("Cancel installation");
@@ -313,26 +166,267 @@ pub fn install_client(
             return Ok(());
         }
 
+        download_all(
+            &[task],
+            download_options,
+            InstallationUpdate::Client,
+            &update_sender,
+            &cancel,
+        )?;
+    }
+
+    Ok(())
+}
+
+// Returns false if the installation got canceled
+pub(crate) fn download_all(
+    tasks: &[DownloadTask],
+    download_options: &DownloadOptions,
+    update: fn(Progress) -> InstallationUpdate,
+    update_sender: &Sender<InstallationUpdate>,
+    cancel: &AtomicBool,
+) -> Result<bool, MinecraftError> {
+    download_files(tasks, download_options, cancel, |progress| {
         // Send update
-        let update_progress = Progress {
-            total_files: 1,
-            current_file: 1,
-            current_file_url: downloads.client.url.clone(),
-            current_file_size: Some(downloads.client.size),
-        };
-        let _ = update_sender.send(InstallationUpdate::Client(update_progress));
+        let _ = update_sender.send(update(progress));
+    })
+    .map_err(MinecraftError::Download)?;
+
+    // Check if canceled
+    if cancel.load(Ordering::Relaxed) {
+        trace!("Cancel installation");
+        let _ = update_sender.send(InstallationUpdate::Cancel);
+        return Ok(false);
+    }
 
-        let mut minecraft_path = PathBuf::from(minecraft_path.as_ref());
-        minecraft_path.push("bin");
+    Ok(true)
+}
 
-        // Create path
-        fs::create_dir_all(&minecraft_path).map_err(MinecraftError::IO)?;
+// Library and native jars, plus the native jars which need to be extracted
+#[allow(clippy::type_complexity)]
+pub(crate) fn library_tasks<'a>(
+    version_data: &'a VersionData,
+    libraries_path: impl AsRef<Path>,
+    endpoints: &Endpoints,
+) -> Result<(Vec<DownloadTask>, Vec<(PathBuf, &'a [String])>), MinecraftError> {
+    let mut tasks = vec![];
+    let mut natives = vec![];
 
-        minecraft_path.push(format!("minecraft-{}-client.jar", &version_data.id));
+    for library in version_data.needed_libraries() {
+        trace!("Library: {}", &library.name);
+        let coordinate = library.coordinate()?;
+
+        // Artifacts without a URL are provided by the loader installer
+        if let Some(artifact) = library
+            .artifact()?
+            .filter(|artifact| !artifact.url.is_empty())
+        {
+            let jar_path = PathBuf::from(libraries_path.as_ref()).join(coordinate.path());
+
+            // Maven repositories do not always declare checksums
+            let jar_sha = match artifact.sha1.is_empty() {
+                true => None,
+                false => Some(hex::decode(&artifact.sha1).map_err(MinecraftError::Sha1Decode)?),
+            };
+            tasks.push(DownloadTask {
+                url: endpoints.rewrite_url(&artifact.url),
+                dest: jar_path,
+                sha1: jar_sha,
+                size: (artifact.size > 0).then_some(artifact.size),
+            });
+        }
 
-        let client_sha = hex::decode(&downloads.client.sha1).map_err(MinecraftError::Sha1Decode)?;
-        download_file_check(&downloads.client.url, &minecraft_path, Some(client_sha))
-            .map_err(MinecraftError::Download)?;
+        // Native
+        if let Some(native) = library.get_native() {
+            trace!("Native: {}", &library.name);
+            let native_coordinate = coordinate.with_classifier(&native);
+            let native_jar_path =
+                PathBuf::from(libraries_path.as_ref()).join(native_coordinate.path());
+
+            // Prefer the download declared in the classifiers
+            let task = match library.get_native_file() {
+                Some(native_file) => DownloadTask {
+                    url: endpoints.rewrite_url(&native_file.url),
+                    dest: native_jar_path.clone(),
+                    sha1: Some(hex::decode(&native_file.sha1).map_err(MinecraftError::Sha1Decode)?),
+                    size: Some(native_file.size),
+                },
+                None => DownloadTask {
+                    url: endpoints.library_url(&native_coordinate.url_path()),
+                    dest: native_jar_path.clone(),
+                    sha1: None,
+                    size: None,
+                },
+            };
+            tasks.push(task);
+
+            if let Some(extract) = &library.extract {
+                natives.push((native_jar_path, extract.exclude.as_slice()));
+            }
+        }
+    }
+
+    Ok((tasks, natives))
+}
+
+pub(crate) fn asset_tasks(
+    asset_index: &AssetIndexData,
+    assets_path: impl AsRef<Path>,
+    endpoints: &Endpoints,
+) -> Result<Vec<DownloadTask>, MinecraftError> {
+    let mut tasks = vec![];
+    let mut hashes = HashSet::new();
+    for asset_info in asset_index.objects.values() {
+        // The same object is often listed under several names
+        if !hashes.insert(&asset_info.hash) {
+            continue;
+        }
+        trace!("Asset: {}", &asset_info.hash);
+        let asset_path = object_path(&assets_path, &asset_info.hash);
+
+        let asset_sha = hex::decode(&asset_info.hash).map_err(MinecraftError::Sha1Decode)?;
+        tasks.push(DownloadTask {
+            url: endpoints.asset_url(&asset_info.hash),
+            dest: asset_path,
+            sha1: Some(asset_sha),
+            size: Some(asset_info.size),
+        });
+    }
+
+    Ok(tasks)
+}
+
+fn object_path(assets_path: impl AsRef<Path>, hash: &str) -> PathBuf {
+    let hash_part: String = hash.chars().take(2).collect();
+
+    let mut path = PathBuf::from(assets_path.as_ref());
+    path.push("objects");
+    path.push(&hash_part);
+    path.push(hash);
+    path
+}
+
+pub(crate) fn resource_tasks(
+    asset_index: &AssetIndexData,
+    resources_path: impl AsRef<Path>,
+    endpoints: &Endpoints,
+) -> Result<Vec<DownloadTask>, MinecraftError> {
+    let mut tasks = vec![];
+    for (key, asset_info) in &asset_index.objects {
+        trace!("Resource: {}", key);
+
+        let mut resource_path = PathBuf::from(resources_path.as_ref());
+        resource_path.push(key);
+
+        let resource_sha = hex::decode(&asset_info.hash).map_err(MinecraftError::Sha1Decode)?;
+        tasks.push(DownloadTask {
+            url: endpoints.asset_url(&asset_info.hash),
+            dest: resource_path,
+            sha1: Some(resource_sha),
+            size: Some(asset_info.size),
+        });
+    }
+
+    Ok(tasks)
+}
+
+pub(crate) fn log_config_task(
+    version_data: &VersionData,
+    log_configs_path: impl AsRef<Path>,
+    endpoints: &Endpoints,
+) -> Result<Option<DownloadTask>, MinecraftError> {
+    let logging_info = match &version_data.logging {
+        None => return Ok(None),
+        Some(logging_info) => logging_info,
+    };
+
+    let mut config_path = PathBuf::from(log_configs_path.as_ref());
+    config_path.push(
+        logging_info
+            .client
+            .file
+            .id
+            .as_ref()
+            .expect("Logging info has no ID"),
+    );
+
+    let config_sha =
+        hex::decode(&logging_info.client.file.sha1).map_err(MinecraftError::Sha1Decode)?;
+
+    Ok(Some(DownloadTask {
+        url: endpoints.rewrite_url(&logging_info.client.file.url),
+        dest: config_path,
+        sha1: Some(config_sha),
+        size: Some(logging_info.client.file.size),
+    }))
+}
+
+pub(crate) fn client_task(
+    version_data: &VersionData,
+    minecraft_path: impl AsRef<Path>,
+    endpoints: &Endpoints,
+) -> Result<Option<DownloadTask>, MinecraftError> {
+    let downloads = match &version_data.downloads {
+        None => return Ok(None),
+        Some(downloads) => downloads,
+    };
+
+    let mut client_path = PathBuf::from(minecraft_path.as_ref());
+    client_path.push("bin");
+    client_path.push(format!(
+        "minecraft-{}-client.jar",
+        version_data.client_jar_id()
+    ));
+
+    let client_sha = hex::decode(&downloads.client.sha1).map_err(MinecraftError::Sha1Decode)?;
+
+    Ok(Some(DownloadTask {
+        url: endpoints.rewrite_url(&downloads.client.url),
+        dest: client_path,
+        sha1: Some(client_sha),
+        size: Some(downloads.client.size),
+    }))
+}
+
+pub(crate) fn extract_natives(
+    natives: &[(PathBuf, &[String])],
+    natives_path: impl AsRef<Path>,
+) -> Result<(), MinecraftError> {
+    for (native_jar_path, exclude) in natives {
+        extract_native(native_jar_path, &natives_path, exclude)?;
+    }
+
+    Ok(())
+}
+
+// Files which `extract_natives` creates, with their content
+pub(crate) fn native_files(
+    natives: &[(PathBuf, &[String])],
+    natives_path: impl AsRef<Path>,
+) -> Result<Vec<(PathBuf, Vec<u8>)>, MinecraftError> {
+    let mut files = vec![];
+    for (native_jar_path, exclude) in natives {
+        files.extend(utils::native_files(
+            native_jar_path,
+            &natives_path,
+            exclude,
+        )?);
+    }
+
+    Ok(files)
+}
+
+pub(crate) fn link_resources(
+    resources_path: impl AsRef<Path>,
+    minecraft_path: impl AsRef<Path>,
+) -> Result<(), MinecraftError> {
+    let mut minecraft_resources_path = PathBuf::from(minecraft_path.as_ref());
+    minecraft_resources_path.push("resources");
+
+    if !minecraft_resources_path.exists() {
+        trace!("Creating symlink for resources");
+        symlink::symlink_dir(&resources_path, minecraft_resources_path)
+            .map_err(MinecraftError::IO)?;
     }
 
     Ok(())
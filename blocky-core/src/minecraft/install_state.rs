@@ -0,0 +1,20 @@
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InstallState {
+    NotInstalled,
+    Partial(Vec<InstallComponent>),
+    Installed,
+    // Version data does not match the version of the instance or was republished
+    Outdated,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InstallComponent {
+    AssetIndex,
+    Libraries,
+    Natives,
+    Assets,
+    LogConfig,
+    Client,
+    // Files created by the installer of the mod loader
+    Loader,
+}
@@ -1,10 +1,18 @@
+use std::time::Duration;
+
 #[derive(Clone, Debug)]
 pub enum InstallationUpdate {
     Library(Progress),
     Asset(Progress),
     LogConfig(Progress),
     Client(Progress),
+    JavaRuntime(Progress),
+    // Processors of mod loader installers
+    Loader(Progress),
+    // Files declared by modpacks
+    Modpack(Progress),
     Cancel,
+    Failed(String),
     Success,
 }
 
@@ -15,6 +23,9 @@ impl InstallationUpdate {
             InstallationUpdate::Asset(_) => "Asset".to_string(),
             InstallationUpdate::LogConfig(_) => "LogConfig".to_string(),
             InstallationUpdate::Client(_) => "Client".to_string(),
+            InstallationUpdate::JavaRuntime(_) => "JavaRuntime".to_string(),
+            InstallationUpdate::Loader(_) => "Loader".to_string(),
+            InstallationUpdate::Modpack(_) => "Modpack".to_string(),
             _ => "Other".to_string(),
         }
     }
@@ -26,4 +37,22 @@ pub struct Progress {
     pub current_file: usize,
     pub current_file_url: String,
     pub current_file_size: Option<usize>,
+    pub current_file_bytes: u64,
+    // Whole phase
+    pub downloaded_bytes: u64,
+    pub total_bytes: u64,
+    // Bytes per second
+    pub speed: f64,
+    pub eta: Option<Duration>,
+}
+
+impl Progress {
+    // Prefers byte progress and falls back to the file count
+    pub fn fraction(&self) -> f64 {
+        match (self.total_bytes, self.total_files) {
+            (0, 0) => 0.0,
+            (0, total_files) => self.current_file as f64 / total_files as f64,
+            (total_bytes, _) => (self.downloaded_bytes as f64 / total_bytes as f64).min(1.0),
+        }
+    }
 }
@@ -1,8 +1,12 @@
+use crate::java::discovery::{probe_java_cached, resolve_java_exec};
+use crate::java::models::java_installation::JavaCompatibility;
+use crate::java::runtime::{is_runtime_installed, java_exec_path};
 use crate::minecraft::argument_replacements::ArgumentReplacements;
 use crate::minecraft::error::MinecraftError;
+use crate::minecraft::launch_features::LaunchFeatures;
 use crate::minecraft::launch_options::LaunchOptions;
 use crate::minecraft::models::version_data::VersionData;
-use crate::os::Platform;
+use crate::os::{Architecture, Platform};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
@@ -32,11 +36,13 @@ pub fn launch(mut command: Command) -> Result<i32, MinecraftError> {
     Ok(0)
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn launch_command(
     version_data: &VersionData,
     minecraft_path: impl AsRef<Path>,
     libraries_path: impl AsRef<Path>,
     assets_path: impl AsRef<Path>,
+    game_assets_path: impl AsRef<Path>,
     natives_path: impl AsRef<Path>,
     log_configs_path: impl AsRef<Path>,
     launch_options: &LaunchOptions,
@@ -52,6 +58,10 @@ pub fn launch_command(
         libraries_path.as_ref().to_string_lossy()
     );
     trace!("Assets Path: {}", assets_path.as_ref().to_string_lossy());
+    trace!(
+        "Game Assets Path: {}",
+        game_assets_path.as_ref().to_string_lossy()
+    );
     trace!("Natives Path: {}", natives_path.as_ref().to_string_lossy());
     trace!(
         "Log Configs Path: {}",
@@ -65,13 +75,34 @@ pub fn launch_command(
         classpath,
         minecraft_path.as_ref().to_string_lossy().to_string(),
         assets_path.as_ref().to_string_lossy().to_string(),
+        game_assets_path.as_ref().to_string_lossy().to_string(),
         natives_path.as_ref().to_string_lossy().to_string(),
+        libraries_path.as_ref().to_string_lossy().to_string(),
+    );
+    let launch_features = LaunchFeatures::from_options(launch_options);
+    trace!("Launch Features: {:?}", &launch_features);
+    let jvm_args = build_jvm_args(
+        version_data,
+        &argument_replacements,
+        &launch_features,
+        launch_options,
+    );
+    let game_args = build_game_args(
+        version_data,
+        &argument_replacements,
+        &launch_features,
+        launch_options,
     );
-    let jvm_args = build_jvm_args(version_data, &argument_replacements, launch_options);
-    let game_args = build_game_args(version_data, &argument_replacements, launch_options);
 
     // Build command
-    let mut command = Command::new(get_java_exec(launch_options));
+    let java_exec = get_java_exec(
+        &launch_options.java_exec,
+        launch_options.java_runtimes_path.as_deref(),
+        version_data,
+    );
+    check_java(&java_exec, version_data)?;
+
+    let mut command = Command::new(java_exec);
     command.current_dir(&minecraft_path);
 
     // JVM arguments
@@ -105,17 +136,67 @@ pub fn launch_command(
     Ok(command)
 }
 
-fn get_java_exec(launch_options: &LaunchOptions) -> String {
-    if !launch_options.java_exec.is_empty() {
-        return launch_options.java_exec.clone();
+// `java_exec` takes precedence over the managed runtime, `PATH` is the last resort
+pub(crate) fn get_java_exec(
+    java_exec: &str,
+    java_runtimes_path: Option<&Path>,
+    version_data: &VersionData,
+) -> String {
+    if !java_exec.is_empty() {
+        return java_exec.to_string();
+    }
+
+    if let Some(runtimes_path) = java_runtimes_path {
+        let component = version_data.java_component();
+        if is_runtime_installed(runtimes_path, component) {
+            return java_exec_path(runtimes_path, component)
+                .to_string_lossy()
+                .to_string();
+        }
+
+        warn!("Java runtime '{}' is not installed", component);
     }
 
     String::from("java")
 }
 
+pub(crate) fn check_java(
+    java_exec: &str,
+    version_data: &VersionData,
+) -> Result<(), MinecraftError> {
+    let java_exec_path = resolve_java_exec(java_exec)
+        .ok_or_else(|| MinecraftError::JavaNotFound(java_exec.to_string()))?;
+    let installation = probe_java_cached(&java_exec_path)?;
+    trace!("Java: {}", installation.display_name());
+
+    let required_major = version_data.java_major_version();
+    match installation.compatibility(required_major) {
+        JavaCompatibility::Exact => {}
+        JavaCompatibility::Newer => warn!(
+            "Java {} is newer than the required Java {} and might be incompatible",
+            installation.major_version, required_major
+        ),
+        JavaCompatibility::Older => {
+            return Err(MinecraftError::JavaVersionMismatch(
+                java_exec.to_string(),
+                installation.major_version,
+                required_major,
+            ))
+        }
+    }
+
+    // Natives are extracted for the architecture of the system
+    if !installation.is_64bit() && Architecture::current().get_bits() == 64 {
+        return Err(MinecraftError::Java32Bit(java_exec.to_string()));
+    }
+
+    Ok(())
+}
+
 fn build_game_args(
     version_data: &VersionData,
     argument_replacements: &ArgumentReplacements,
+    launch_features: &LaunchFeatures,
     launch_options: &LaunchOptions,
 ) -> Vec<String> {
     debug!("Building Minecraft Args");
@@ -124,27 +205,34 @@ fn build_game_args(
 
     // New style
     if let Some(args) = &version_data.arguments {
-        for argument in args.game_arguments() {
+        for argument in args.game_arguments(launch_features) {
             arguments.push(argument_replacements.replace(&argument));
         }
     }
 
-    // Old style
+    // Old style, which has no arguments for features
     if let Some(args) = &version_data.minecraft_arguments {
         for argument in args.split_whitespace() {
             arguments.push(argument_replacements.replace(argument));
         }
+
+        if version_data.arguments.is_none() {
+            if launch_features.is_demo_user {
+                arguments.push("--demo".to_string());
+            }
+            if launch_features.has_custom_resolution {
+                arguments.extend_from_slice(&[
+                    "--width".to_string(),
+                    launch_options.window_width.to_string(),
+                    "--height".to_string(),
+                    launch_options.window_height.to_string(),
+                ]);
+            }
+        }
     }
 
     if launch_options.use_fullscreen {
         arguments.push("--fullscreen".to_string());
-    } else if launch_options.enable_window_size {
-        arguments.extend_from_slice(&[
-            "--width".to_string(),
-            launch_options.window_width.to_string(),
-            "--height".to_string(),
-            launch_options.window_height.to_string(),
-        ]);
     }
 
     arguments
@@ -153,6 +241,7 @@ fn build_game_args(
 fn build_jvm_args(
     version_data: &VersionData,
     argument_replacements: &ArgumentReplacements,
+    launch_features: &LaunchFeatures,
     launch_options: &LaunchOptions,
 ) -> Vec<String> {
     debug!("Building JVM Args");
@@ -171,7 +260,7 @@ fn build_jvm_args(
         }
     } else if let Some(args) = &version_data.arguments {
         // Version Data
-        for argument in args.jvm_arguments() {
+        for argument in args.jvm_arguments(launch_features) {
             arguments.push(argument_replacements.replace(&argument));
         }
     }
@@ -203,28 +292,21 @@ fn build_classpath(
     let mut classes: Vec<String> = vec![];
 
     for library in version_data.needed_libraries() {
-        let (mut package, name, version) = library.extract_information()?;
-        package = package.replace('.', "/");
-
-        let mut library_path = PathBuf::from(libraries_path.as_ref());
-        library_path.push(&package);
-        library_path.push(&name);
-        library_path.push(&version);
-
-        if let Some(native) = &library.get_native() {
-            let native_jar_name = format!("{}-{}-{}.jar", name, version, native);
-            library_path.push(native_jar_name);
-        } else {
-            let jar_name = format!("{}-{}.jar", name, version);
-            library_path.push(jar_name);
+        let mut coordinate = library.coordinate()?;
+        if let Some(native) = library.get_native() {
+            coordinate = coordinate.with_classifier(&native);
         }
 
+        let library_path = PathBuf::from(libraries_path.as_ref()).join(coordinate.path());
         classes.push(library_path.to_string_lossy().to_string());
     }
 
     let mut minecraft_path = PathBuf::from(minecraft_path.as_ref());
     minecraft_path.push("bin");
-    minecraft_path.push(format!("minecraft-{}-client.jar", &version_data.id));
+    minecraft_path.push(format!(
+        "minecraft-{}-client.jar",
+        version_data.client_jar_id()
+    ));
 
     classes.push(minecraft_path.to_string_lossy().to_string());
 
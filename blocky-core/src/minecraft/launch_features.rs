@@ -0,0 +1,38 @@
+use crate::minecraft::launch_options::LaunchOptions;
+
+// Features which arguments of the version data can depend on
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LaunchFeatures {
+    pub has_custom_resolution: bool,
+    pub is_demo_user: bool,
+    pub has_quick_plays_support: bool,
+    pub is_quick_play_singleplayer: bool,
+    pub is_quick_play_multiplayer: bool,
+    pub is_quick_play_realms: bool,
+}
+
+impl LaunchFeatures {
+    pub fn from_options(options: &LaunchOptions) -> Self {
+        let quick_play = options.quick_play.as_ref();
+
+        Self {
+            has_custom_resolution: options.enable_window_size && !options.use_fullscreen,
+            is_demo_user: options.demo_user,
+            has_quick_plays_support: options.quick_play_path.is_some(),
+            is_quick_play_singleplayer: matches!(quick_play, Some(QuickPlay::Singleplayer(_))),
+            is_quick_play_multiplayer: matches!(quick_play, Some(QuickPlay::Multiplayer(_))),
+            is_quick_play_realms: matches!(quick_play, Some(QuickPlay::Realms(_))),
+        }
+    }
+}
+
+// Joins a world, server or realm right after the game started
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum QuickPlay {
+    // Name of the world directory in `saves`
+    Singleplayer(String),
+    // Server address, e.g. `example.com:25565`
+    Multiplayer(String),
+    // Realm ID
+    Realms(String),
+}
@@ -1,5 +1,7 @@
+use crate::minecraft::launch_features::QuickPlay;
 use derive_builder::Builder;
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 #[derive(Builder, Clone, Debug)]
 pub struct LaunchOptions {
@@ -26,14 +28,27 @@ pub struct LaunchOptions {
     #[builder(default = "720")]
     pub window_height: u32,
     #[builder(default)]
+    pub demo_user: bool,
+    #[builder(default)]
+    #[builder(setter(strip_option))]
+    pub quick_play: Option<QuickPlay>,
+    // File the game logs quick play entries to
+    #[builder(default)]
+    #[builder(setter(strip_option))]
+    pub quick_play_path: Option<PathBuf>,
+    #[builder(default)]
     pub enable_memory: bool,
     #[builder(default = "512")]
     pub min_memory: u32,
     #[builder(default = "1024")]
     pub max_memory: u32,
-    #[builder(default = "String::from(\"java\")")]
+    // Resolved from `java_runtimes_path` or `PATH` if empty
+    #[builder(default)]
     pub java_exec: String,
     #[builder(default)]
+    #[builder(setter(strip_option))]
+    pub java_runtimes_path: Option<PathBuf>,
+    #[builder(default)]
     pub enable_jvm_args: bool,
     #[builder(default)]
     pub jvm_args: String,
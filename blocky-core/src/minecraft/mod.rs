@@ -1,8 +1,13 @@
 mod argument_replacements;
+pub mod download_options;
 pub(crate) mod error;
+pub mod inheritance;
 pub(crate) mod install;
+pub mod install_state;
 pub mod installation_update;
 pub(crate) mod launch;
+pub mod launch_features;
 pub mod launch_options;
 pub mod models;
 mod utils;
+pub mod verify_report;
@@ -1,4 +1,5 @@
 use crate::either::Either;
+use crate::minecraft::launch_features::LaunchFeatures;
 use crate::minecraft::models::rule::Rule;
 use serde::{Deserialize, Serialize};
 
@@ -9,15 +10,15 @@ pub struct Arguments {
 }
 
 impl Arguments {
-    pub fn jvm_arguments(&self) -> Vec<String> {
-        Self::collect_args(&self.jvm)
+    pub fn jvm_arguments(&self, launch_features: &LaunchFeatures) -> Vec<String> {
+        Self::collect_args(&self.jvm, launch_features)
     }
 
-    pub fn game_arguments(&self) -> Vec<String> {
-        Self::collect_args(&self.game)
+    pub fn game_arguments(&self, launch_features: &LaunchFeatures) -> Vec<String> {
+        Self::collect_args(&self.game, launch_features)
     }
 
-    fn collect_args(args: &[Argument]) -> Vec<String> {
+    fn collect_args(args: &[Argument], launch_features: &LaunchFeatures) -> Vec<String> {
         let mut arguments = vec![];
 
         for argument in args {
@@ -30,7 +31,7 @@ impl Arguments {
                     arguments.push(simple_argument.to_string());
                 }
                 Argument::Complex(complex_arg) => {
-                    if !complex_arg.check_use() {
+                    if !complex_arg.check_use(launch_features) {
                         continue;
                     }
 
@@ -63,9 +64,9 @@ pub struct ComplexArgument {
 }
 
 impl ComplexArgument {
-    pub fn check_use(&self) -> bool {
+    pub fn check_use(&self, launch_features: &LaunchFeatures) -> bool {
         for rule in &self.rules {
-            if !rule.allows() {
+            if !rule.allows(launch_features) {
                 return false;
             }
         }
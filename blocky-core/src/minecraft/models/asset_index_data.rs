@@ -5,9 +5,33 @@ use std::collections::HashMap;
 pub struct AssetIndexData {
     #[serde(default)]
     pub map_to_resources: bool,
+    #[serde(default, rename = "virtual")]
+    pub is_virtual: bool,
     pub objects: HashMap<String, AssetInfo>,
 }
 
+impl AssetIndexData {
+    pub fn layout(&self) -> AssetLayout {
+        if self.map_to_resources {
+            AssetLayout::Resources
+        } else if self.is_virtual {
+            AssetLayout::Virtual
+        } else {
+            AssetLayout::Objects
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AssetLayout {
+    // Assets are only stored by hash in `assets/objects`
+    Objects,
+    // Assets are additionally copied by name into `assets/virtual/<index>`
+    Virtual,
+    // Assets are additionally copied by name into `.minecraft/resources`
+    Resources,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AssetInfo {
     pub hash: String,
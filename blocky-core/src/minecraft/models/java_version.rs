@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct JavaVersion {
+    pub component: String,
+    #[serde(alias = "majorVersion")]
+    pub major_version: u32,
+}
@@ -1,16 +1,24 @@
+use crate::consts;
 use crate::minecraft::error::MinecraftError;
+use crate::minecraft::launch_features::LaunchFeatures;
 use crate::minecraft::models::extract::Extract;
+use crate::minecraft::models::file::File;
 use crate::minecraft::models::library_downloads::LibraryDownloads;
+use crate::minecraft::models::maven_coordinate::MavenCoordinate;
 use crate::minecraft::models::natives::Natives;
 use crate::minecraft::models::rule::Rule;
 use crate::os::Architecture;
-use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Library {
+    #[serde(default)]
     pub downloads: LibraryDownloads,
     pub name: String,
+    // Maven repository of libraries without downloads, e.g. the ones of mod loaders
+    pub url: Option<String>,
+    pub sha1: Option<String>,
+    pub size: Option<usize>,
     pub natives: Option<Natives>,
     #[serde(default)]
     pub rules: Vec<Rule>,
@@ -20,31 +28,95 @@ pub struct Library {
 impl Library {
     pub fn check_use(&self) -> bool {
         for rule in &self.rules {
-            if !rule.allows() {
+            if !rule.allows(&LaunchFeatures::default()) {
                 return false;
             }
         }
 
-        true
+        match self.native_arch() {
+            Some(arch) => arch.matches_current(),
+            // Natives without an architecture are x86_64 builds
+            None if Architecture::current() == Architecture::Other => !self
+                .classifier()
+                .is_some_and(|classifier| classifier.starts_with("natives-")),
+            None => true,
+        }
     }
 
-    pub fn extract_information(&self) -> Result<(String, String, String), MinecraftError> {
-        self.name
-            .split(':')
-            .map(|x| x.to_string())
-            .collect_tuple()
-            .ok_or(MinecraftError::LibraryNameFormat)
+    pub fn coordinate(&self) -> Result<MavenCoordinate, MinecraftError> {
+        self.name.parse()
+    }
+
+    // Natives of LWJGL 3.3+ are separate libraries with a classifier, e.g.
+    // `org.lwjgl:lwjgl:3.3.1:natives-linux-arm64`
+    pub fn classifier(&self) -> Option<String> {
+        self.coordinate().ok()?.classifier
+    }
+
+    // Architecture of classifiers like `natives-macos-arm64`. Classifiers without one are
+    // meant for x86_64.
+    pub fn native_arch(&self) -> Option<Architecture> {
+        let classifier = self.classifier()?;
+        if !classifier.starts_with("natives-") {
+            return None;
+        }
+
+        let mut parts = classifier.splitn(3, '-').skip(2);
+        parts.next().and_then(Architecture::from_name)
+    }
+
+    // Download of the library jar itself. Libraries which only consist of natives have none.
+    pub fn artifact(&self) -> Result<Option<File>, MinecraftError> {
+        if let Some(artifact) = &self.downloads.artifact {
+            return Ok(Some(artifact.clone()));
+        }
+
+        let url = match (&self.url, &self.natives) {
+            (Some(url), _) => url.clone(),
+            (None, Some(_)) => return Ok(None),
+            (None, None) => String::from(consts::MC_LIBRARIES_BASE_URL),
+        };
+        let coordinate = self.coordinate()?;
+
+        Ok(Some(File {
+            id: None,
+            path: Some(coordinate.url_path()),
+            sha1: self.sha1.clone().unwrap_or_default(),
+            size: self.size.unwrap_or_default(),
+            url: format!("{}/{}", url.trim_end_matches('/'), coordinate.url_path()),
+        }))
     }
 
     pub fn get_native(&self) -> Option<String> {
         let arch = Architecture::current();
 
-        if let Some(natives) = &self.natives {
-            return natives
-                .get_for_current_platform()
-                .map(|n| n.replace("${arch}", &arch.get_bits().to_string()));
+        let native = self
+            .natives
+            .as_ref()?
+            .get_for_current_platform()?
+            .replace("${arch}", &arch.get_bits().to_string());
+
+        // Prefer builds for the current architecture, e.g. `natives-osx-arm64`
+        let arch_native = format!("{}-{}", native, arch.name());
+        let has_arch_native = self
+            .downloads
+            .classifiers
+            .as_ref()
+            .map(|classifiers| classifiers.contains_key(&arch_native))
+            .unwrap_or(false);
+
+        match has_arch_native {
+            true => Some(arch_native),
+            false => Some(native),
         }
+    }
+
+    pub fn get_native_file(&self) -> Option<&File> {
+        let native = self.get_native()?;
 
-        None
+        self.downloads
+            .classifiers
+            .as_ref()
+            .and_then(|classifiers| classifiers.get(&native))
     }
 }
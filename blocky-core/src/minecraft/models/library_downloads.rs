@@ -1,9 +1,9 @@
 use crate::minecraft::models::file::File;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct LibraryDownloads {
-    pub artifact: File,
-    // TODO: properly deserialize
-    pub classifiers: Option<serde_json::Value>,
+    pub artifact: Option<File>,
+    pub classifiers: Option<HashMap<String, File>>,
 }
@@ -0,0 +1,219 @@
+use crate::minecraft::error::MinecraftError;
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+// `group:artifact:version[:classifier][@extension]`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MavenCoordinate {
+    pub group: String,
+    pub artifact: String,
+    pub version: String,
+    pub classifier: Option<String>,
+    pub extension: String,
+}
+
+impl MavenCoordinate {
+    pub fn with_classifier(&self, classifier: &str) -> Self {
+        Self {
+            classifier: Some(classifier.to_string()),
+            ..self.clone()
+        }
+    }
+
+    // e.g. `lwjgl-3.3.1-natives-linux.jar`
+    pub fn file_name(&self) -> String {
+        match &self.classifier {
+            Some(classifier) => format!(
+                "{}-{}-{}.{}",
+                &self.artifact, &self.version, classifier, &self.extension
+            ),
+            None => format!("{}-{}.{}", &self.artifact, &self.version, &self.extension),
+        }
+    }
+
+    // Path relative to the repository root, e.g.
+    // `org/lwjgl/lwjgl/3.3.1/lwjgl-3.3.1-natives-linux.jar`
+    pub fn path(&self) -> PathBuf {
+        let mut path = PathBuf::new();
+        for part in self.group.split('.') {
+            path.push(part);
+        }
+        path.push(&self.artifact);
+        path.push(&self.version);
+        path.push(self.file_name());
+        path
+    }
+
+    // Same as `path` but always separated by `/`
+    pub fn url_path(&self) -> String {
+        format!(
+            "{}/{}/{}/{}",
+            self.group.replace('.', "/"),
+            &self.artifact,
+            &self.version,
+            self.file_name()
+        )
+    }
+}
+
+impl FromStr for MavenCoordinate {
+    type Err = MinecraftError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (coordinate, extension) = match s.split_once('@') {
+            Some((coordinate, extension)) => (coordinate, extension.to_string()),
+            None => (s, String::from("jar")),
+        };
+
+        let parts = coordinate.split(':').collect::<Vec<_>>();
+        let (group, artifact, version, classifier) = match parts.as_slice() {
+            [group, artifact, version] => (group, artifact, version, None),
+            [group, artifact, version, classifier] => {
+                (group, artifact, version, Some(classifier.to_string()))
+            }
+            _ => return Err(MinecraftError::LibraryNameFormat(s.to_string())),
+        };
+
+        // Coordinates come from remote metadata and end up as paths below the libraries
+        let is_plain = group
+            .split('.')
+            .chain([*artifact, *version, extension.as_str()])
+            .all(is_plain_component);
+        if !is_plain
+            || !classifier
+                .as_deref()
+                .map(is_plain_component)
+                .unwrap_or(true)
+        {
+            return Err(MinecraftError::LibraryNameFormat(s.to_string()));
+        }
+
+        Ok(Self {
+            group: group.to_string(),
+            artifact: artifact.to_string(),
+            version: version.to_string(),
+            classifier,
+            extension,
+        })
+    }
+}
+
+fn is_plain_component(part: &str) -> bool {
+    !part.is_empty() && part != "." && part != ".." && !part.contains(['/', '\\', ':'])
+}
+
+impl Display for MavenCoordinate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", &self.group, &self.artifact, &self.version)?;
+        if let Some(classifier) = &self.classifier {
+            write!(f, ":{}", classifier)?;
+        }
+        if self.extension != "jar" {
+            write!(f, "@{}", &self.extension)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn parse_plain() {
+        let coordinate = "org.lwjgl:lwjgl:3.3.1".parse::<MavenCoordinate>().unwrap();
+
+        assert_eq!(coordinate.group, "org.lwjgl");
+        assert_eq!(coordinate.artifact, "lwjgl");
+        assert_eq!(coordinate.version, "3.3.1");
+        assert_eq!(coordinate.classifier, None);
+        assert_eq!(coordinate.extension, "jar");
+        assert_eq!(
+            coordinate.path(),
+            Path::new("org/lwjgl/lwjgl/3.3.1/lwjgl-3.3.1.jar")
+        );
+        assert_eq!(
+            coordinate.url_path(),
+            "org/lwjgl/lwjgl/3.3.1/lwjgl-3.3.1.jar"
+        );
+    }
+
+    #[test]
+    fn parse_classifier() {
+        let coordinate = "org.lwjgl:lwjgl:3.3.1:natives-linux"
+            .parse::<MavenCoordinate>()
+            .unwrap();
+
+        assert_eq!(coordinate.classifier.as_deref(), Some("natives-linux"));
+        assert_eq!(coordinate.extension, "jar");
+        assert_eq!(
+            coordinate.url_path(),
+            "org/lwjgl/lwjgl/3.3.1/lwjgl-3.3.1-natives-linux.jar"
+        );
+    }
+
+    #[test]
+    fn parse_extension() {
+        let coordinate = "de.oceanlabs.mcp:mcp_config:1.19.2-20220805.130853@zip"
+            .parse::<MavenCoordinate>()
+            .unwrap();
+
+        assert_eq!(coordinate.classifier, None);
+        assert_eq!(coordinate.extension, "zip");
+        assert_eq!(
+            coordinate.path(),
+            Path::new(
+                "de/oceanlabs/mcp/mcp_config/1.19.2-20220805.130853/mcp_config-1.19.2-20220805.130853.zip"
+            )
+        );
+    }
+
+    #[test]
+    fn parse_classifier_and_extension() {
+        let coordinate = "net.minecraft:client:1.19.2-20220805.130853:mappings@txt"
+            .parse::<MavenCoordinate>()
+            .unwrap();
+
+        assert_eq!(coordinate.classifier.as_deref(), Some("mappings"));
+        assert_eq!(coordinate.extension, "txt");
+        assert_eq!(
+            coordinate.url_path(),
+            "net/minecraft/client/1.19.2-20220805.130853/client-1.19.2-20220805.130853-mappings.txt"
+        );
+        assert_eq!(
+            coordinate.to_string(),
+            "net.minecraft:client:1.19.2-20220805.130853:mappings@txt"
+        );
+    }
+
+    #[test]
+    fn reject_unsafe_parts() {
+        let names = [
+            "org.lwjgl:lwjgl",
+            "org.lwjgl:lwjgl:3.3.1:natives:extra",
+            "..:lwjgl:3.3.1",
+            "org..lwjgl:lwjgl:3.3.1",
+            "org.lwjgl:..:3.3.1",
+            "org.lwjgl:lwjgl:..",
+            "org.lwjgl:lwjgl:3.3.1:..",
+            "org.lwjgl:lwjgl:3.3.1@..",
+            "org/lwjgl:lwjgl:3.3.1",
+            "org.lwjgl:lw/jgl:3.3.1",
+            "org.lwjgl:lwjgl:3.3.1/../..",
+            "org.lwjgl:lwjgl:3.3.1:natives\\linux",
+            "org.lwjgl:lwjgl:3.3.1@../jar",
+            "org.lwjgl:lwjgl:",
+        ];
+
+        for name in names {
+            assert!(
+                name.parse::<MavenCoordinate>().is_err(),
+                "'{}' should be rejected",
+                name
+            );
+        }
+    }
+}
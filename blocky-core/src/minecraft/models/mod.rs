@@ -4,9 +4,11 @@ pub mod asset_index_data;
 pub mod downloads;
 pub mod extract;
 pub mod file;
+pub mod java_version;
 pub mod library;
 pub mod library_downloads;
 pub mod logging_info;
+pub mod maven_coordinate;
 pub mod natives;
 pub mod rule;
 pub mod version_data;
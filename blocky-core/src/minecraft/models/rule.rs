@@ -1,4 +1,7 @@
+use crate::minecraft::launch_features::LaunchFeatures;
+use crate::os;
 use crate::os::{Architecture, Platform};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -9,18 +12,30 @@ pub struct Rule {
 }
 
 impl Rule {
-    pub fn allows(&self) -> bool {
+    pub fn allows(&self, launch_features: &LaunchFeatures) -> bool {
         if let Some(os) = &self.os {
             if let Some(platform) = &os.platform {
                 if platform != &Platform::current() {
                     return !self.action.to_bool();
                 }
             }
+
+            if let Some(arch) = &os.arch {
+                if !arch.matches_current() {
+                    return !self.action.to_bool();
+                }
+            }
+
+            if let Some(version) = &os.version {
+                if !version_matches(version) {
+                    return !self.action.to_bool();
+                }
+            }
         }
 
         if let Some(features) = &self.features {
-            if features.is_demo_user.is_some() || features.has_custom_resolution.is_some() {
-                return false;
+            if !features.matches(launch_features) {
+                return !self.action.to_bool();
             }
         }
 
@@ -28,6 +43,20 @@ impl Rule {
     }
 }
 
+fn version_matches(pattern: &str) -> bool {
+    let regex = match Regex::new(pattern) {
+        Ok(regex) => regex,
+        Err(err) => {
+            warn!("Invalid OS version pattern '{}': {}", pattern, err);
+            return false;
+        }
+    };
+
+    os::os_version()
+        .map(|version| regex.is_match(&version))
+        .unwrap_or(false)
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum Action {
     #[serde(alias = "allow")]
@@ -57,4 +86,39 @@ pub struct Os {
 pub struct Features {
     pub is_demo_user: Option<bool>,
     pub has_custom_resolution: Option<bool>,
+    pub has_quick_plays_support: Option<bool>,
+    pub is_quick_play_singleplayer: Option<bool>,
+    pub is_quick_play_multiplayer: Option<bool>,
+    pub is_quick_play_realms: Option<bool>,
+}
+
+impl Features {
+    // Every feature listed in the rule has to match
+    pub fn matches(&self, launch_features: &LaunchFeatures) -> bool {
+        [
+            (self.is_demo_user, launch_features.is_demo_user),
+            (
+                self.has_custom_resolution,
+                launch_features.has_custom_resolution,
+            ),
+            (
+                self.has_quick_plays_support,
+                launch_features.has_quick_plays_support,
+            ),
+            (
+                self.is_quick_play_singleplayer,
+                launch_features.is_quick_play_singleplayer,
+            ),
+            (
+                self.is_quick_play_multiplayer,
+                launch_features.is_quick_play_multiplayer,
+            ),
+            (
+                self.is_quick_play_realms,
+                launch_features.is_quick_play_realms,
+            ),
+        ]
+        .into_iter()
+        .all(|(required, actual)| required.map(|required| required == actual).unwrap_or(true))
+    }
 }
@@ -1,9 +1,12 @@
+use crate::consts;
 use crate::minecraft::models::arguments::Arguments;
 use crate::minecraft::models::asset_index::AssetIndex;
 use crate::minecraft::models::downloads::Downloads;
+use crate::minecraft::models::java_version::JavaVersion;
 use crate::minecraft::models::library::Library;
 use crate::minecraft::models::logging_info::LoggingInfo;
 use crate::minecraft::models::version_type::VersionType;
+use crate::os::Architecture;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -17,9 +20,12 @@ pub struct VersionData {
     pub compliance_level: i32,
     pub downloads: Option<Downloads>,
     pub id: String,
-    // TODO: properly deserialize
+    #[serde(alias = "inheritsFrom")]
+    pub inherits_from: Option<String>,
+    // Version whose client jar is used
+    pub jar: Option<String>,
     #[serde(alias = "javaVersion")]
-    pub java_version: serde_json::Value,
+    pub java_version: Option<JavaVersion>,
     pub libraries: Vec<Library>,
     pub logging: Option<LoggingInfo>,
     #[serde(alias = "mainClass")]
@@ -37,9 +43,46 @@ pub struct VersionData {
 
 impl VersionData {
     pub fn needed_libraries(&self) -> Vec<&Library> {
-        self.libraries
+        let libraries = self
+            .libraries
             .iter()
             .filter(|library| library.check_use())
+            .collect::<Vec<_>>();
+
+        // Natives without an architecture are for x86_64 and are skipped if a build for the
+        // current architecture is listed as well
+        libraries
+            .iter()
+            .copied()
+            .filter(|library| match library.classifier() {
+                Some(classifier)
+                    if classifier.starts_with("natives-") && library.native_arch().is_none() =>
+                {
+                    let arch_native =
+                        format!("{}-{}", &library.name, Architecture::current().name());
+                    !libraries.iter().any(|other| other.name == arch_native)
+                }
+                _ => true,
+            })
             .collect()
     }
+
+    pub fn client_jar_id(&self) -> &str {
+        self.jar.as_deref().unwrap_or(&self.id)
+    }
+
+    // Versions without Java requirements run on Java 8
+    pub fn java_component(&self) -> &str {
+        self.java_version
+            .as_ref()
+            .map(|java_version| java_version.component.as_str())
+            .unwrap_or(consts::DEFAULT_JAVA_COMPONENT)
+    }
+
+    pub fn java_major_version(&self) -> u32 {
+        self.java_version
+            .as_ref()
+            .map(|java_version| java_version.major_version)
+            .unwrap_or(consts::DEFAULT_JAVA_MAJOR_VERSION)
+    }
 }
@@ -1,5 +1,6 @@
-use crate::consts;
-use crate::error::DownloadError;
+use crate::cache;
+use crate::endpoints::Endpoints;
+use crate::minecraft::download_options::DownloadOptions;
 use crate::minecraft::error::MinecraftError;
 use crate::minecraft::models::version_summary::VersionSummary;
 use serde::{Deserialize, Serialize};
@@ -12,15 +13,14 @@ pub struct VersionManifest {
 }
 
 impl VersionManifest {
-    pub fn get() -> Result<Self, MinecraftError> {
-        let response = reqwest::blocking::Client::new()
-            .get(consts::MC_VERSION_MANIFEST_URL)
-            .send()
-            .map_err(|err| MinecraftError::Download(DownloadError::Reqwest(err)))?
-            .error_for_status()
-            .map_err(|err| MinecraftError::Download(DownloadError::Reqwest(err)))?
-            .json::<VersionManifestResponse>()
-            .map_err(|err| MinecraftError::Download(DownloadError::Reqwest(err)))?;
+    pub fn get(
+        endpoints: &Endpoints,
+        download_options: &DownloadOptions,
+    ) -> Result<Self, MinecraftError> {
+        let response = cache::get(&endpoints.version_manifest_url, download_options)
+            .map_err(MinecraftError::Download)?;
+        let response = serde_json::from_slice::<VersionManifestResponse>(&response)
+            .map_err(MinecraftError::Serde)?;
 
         Ok(response.into())
     }
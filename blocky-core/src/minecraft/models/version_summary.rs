@@ -11,4 +11,8 @@ pub struct VersionSummary {
     pub time: DateTime<Utc>,
     #[serde(alias = "releaseTime")]
     pub release_time: DateTime<Utc>,
+    // Only provided by the v2 manifest
+    pub sha1: Option<String>,
+    #[serde(alias = "complianceLevel")]
+    pub compliance_level: Option<u32>,
 }
@@ -1,5 +1,6 @@
 use crate::minecraft::error::MinecraftError;
 use std::fs;
+use std::io::Read;
 use std::path::{Path, PathBuf};
 
 pub fn extract_native(
@@ -18,21 +19,11 @@ pub fn extract_native(
 
     'files: for i in 0..archive.len() {
         let mut file = archive.by_index(i).unwrap();
-        let zip_path = match file.enclosed_name() {
+        let out_path = match native_out_path(&file, &destination, excludes) {
             None => continue 'files,
-            Some(path) => path.to_owned(),
+            Some(path) => path,
         };
 
-        // Check excludes
-        for exclude in excludes {
-            if zip_path.starts_with(exclude) {
-                continue 'files;
-            }
-        }
-
-        let mut out_path = PathBuf::from(destination.as_ref());
-        out_path.push(zip_path);
-
         if out_path.is_file() && file.is_file() {
             // File exists => skip
             continue 'files;
@@ -53,3 +44,42 @@ pub fn extract_native(
 
     Ok(())
 }
+
+// Files `extract_native` creates from `file` together with their content
+pub fn native_files(
+    file: impl AsRef<Path>,
+    destination: impl AsRef<Path>,
+    excludes: &[String],
+) -> Result<Vec<(PathBuf, Vec<u8>)>, MinecraftError> {
+    let zip_file = fs::File::open(&file).map_err(MinecraftError::IO)?;
+    let mut archive = zip::ZipArchive::new(zip_file).map_err(MinecraftError::Extract)?;
+
+    let mut files = vec![];
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i).map_err(MinecraftError::Extract)?;
+        if !file.is_file() {
+            continue;
+        }
+
+        if let Some(out_path) = native_out_path(&file, &destination, excludes) {
+            let mut data = vec![];
+            file.read_to_end(&mut data).map_err(MinecraftError::IO)?;
+            files.push((out_path, data));
+        }
+    }
+
+    Ok(files)
+}
+
+fn native_out_path(
+    file: &zip::read::ZipFile,
+    destination: impl AsRef<Path>,
+    excludes: &[String],
+) -> Option<PathBuf> {
+    let zip_path = file.enclosed_name()?;
+    if excludes.iter().any(|exclude| zip_path.starts_with(exclude)) {
+        return None;
+    }
+
+    Some(destination.as_ref().join(zip_path))
+}
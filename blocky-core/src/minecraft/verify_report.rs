@@ -0,0 +1,86 @@
+use crate::error::DownloadError;
+use crate::utils::{get_sha1, part_path, DownloadTask};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, Default)]
+pub struct VerifyReport {
+    pub checked_files: usize,
+    pub missing: Vec<PathBuf>,
+    pub size_mismatch: Vec<PathBuf>,
+    pub hash_mismatch: Vec<PathBuf>,
+    // Leftovers of interrupted downloads and files in the natives folder which no native jar
+    // contains. Libraries and assets are shared with other instances, so unknown files there
+    // are not reported.
+    pub extraneous: Vec<PathBuf>,
+}
+
+impl VerifyReport {
+    pub fn is_intact(&self) -> bool {
+        self.missing.is_empty()
+            && self.size_mismatch.is_empty()
+            && self.hash_mismatch.is_empty()
+            && self.extraneous.is_empty()
+    }
+
+    pub fn broken_files(&self) -> usize {
+        self.missing.len() + self.size_mismatch.len() + self.hash_mismatch.len()
+    }
+
+    pub fn is_broken(&self, path: impl AsRef<Path>) -> bool {
+        let path = path.as_ref();
+
+        self.missing.iter().any(|p| p == path)
+            || self.size_mismatch.iter().any(|p| p == path)
+            || self.hash_mismatch.iter().any(|p| p == path)
+    }
+
+    // Files without a checksum, e.g. extracted natives, are compared with their expected content
+    pub(crate) fn check_content(&mut self, path: &Path, data: &[u8]) {
+        trace!("Verifying file: {}", path.to_string_lossy());
+        self.checked_files += 1;
+
+        match fs::read(path) {
+            Err(_) => self.missing.push(path.to_path_buf()),
+            Ok(content) if content.len() != data.len() => {
+                self.size_mismatch.push(path.to_path_buf())
+            }
+            Ok(content) if content != data => self.hash_mismatch.push(path.to_path_buf()),
+            Ok(_) => {}
+        }
+    }
+
+    pub(crate) fn check_file(&mut self, task: &DownloadTask) -> Result<(), DownloadError> {
+        trace!("Verifying file: {}", task.dest.to_string_lossy());
+        self.checked_files += 1;
+
+        // Leftovers of interrupted downloads
+        let part_path = part_path(&task.dest);
+        if part_path.exists() {
+            self.extraneous.push(part_path);
+        }
+
+        let metadata = match fs::metadata(&task.dest) {
+            Ok(metadata) => metadata,
+            Err(_) => {
+                self.missing.push(task.dest.clone());
+                return Ok(());
+            }
+        };
+
+        if let Some(size) = task.size {
+            if metadata.len() != size as u64 {
+                self.size_mismatch.push(task.dest.clone());
+                return Ok(());
+            }
+        }
+
+        if let Some(sha1) = &task.sha1 {
+            if &get_sha1(&task.dest)? != sha1 {
+                self.hash_mismatch.push(task.dest.clone());
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use crate::modpack::error::ModpackError;
+use crate::utils;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+pub(crate) fn open_archive(
+    path: impl AsRef<Path>,
+) -> Result<zip::ZipArchive<fs::File>, ModpackError> {
+    let file = fs::File::open(path).map_err(ModpackError::IO)?;
+    zip::ZipArchive::new(file).map_err(ModpackError::Extract)
+}
+
+pub(crate) fn read_entry(
+    archive_path: impl AsRef<Path>,
+    name: &str,
+) -> Result<Vec<u8>, ModpackError> {
+    let mut archive = open_archive(archive_path)?;
+    let mut entry = archive.by_name(name).map_err(ModpackError::Extract)?;
+
+    let mut data = vec![];
+    entry.read_to_end(&mut data).map_err(ModpackError::IO)?;
+
+    Ok(data)
+}
+
+// Copies the contents of `folder` inside the archive into `dest`
+pub(crate) fn extract_folder(
+    archive_path: impl AsRef<Path>,
+    folder: &str,
+    dest: impl AsRef<Path>,
+) -> Result<(), ModpackError> {
+    let mut archive = open_archive(archive_path)?;
+
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i).map_err(ModpackError::Extract)?;
+        let relative = match entry
+            .enclosed_name()
+            .and_then(|name| name.strip_prefix(folder).ok())
+        {
+            Some(relative) if relative.components().next().is_some() => relative.to_path_buf(),
+            _ => continue,
+        };
+
+        let path = dest.as_ref().join(relative);
+        if entry.is_dir() {
+            fs::create_dir_all(&path).map_err(ModpackError::IO)?;
+            continue;
+        }
+
+        trace!("Extracting: {}", path.to_string_lossy());
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(ModpackError::IO)?;
+        }
+        let mut file = fs::File::create(&path).map_err(ModpackError::IO)?;
+        std::io::copy(&mut entry, &mut file).map_err(ModpackError::IO)?;
+    }
+
+    Ok(())
+}
+
+// Paths declared by modpacks are relative to `.minecraft` and must not leave it
+pub(crate) fn enclosed_path(
+    base: impl AsRef<Path>,
+    relative: &str,
+) -> Result<PathBuf, ModpackError> {
+    utils::enclosed_path(base, relative)
+        .ok_or_else(|| ModpackError::UnsafePath(relative.to_string()))
+}
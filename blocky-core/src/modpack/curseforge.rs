@@ -0,0 +1,404 @@
+use crate::endpoints::Endpoints;
+use crate::error::DownloadError;
+use crate::instance::InstanceBuilder;
+use crate::minecraft::download_options::DownloadOptions;
+use crate::minecraft::install::download_all;
+use crate::minecraft::installation_update::{InstallationUpdate, Progress};
+use crate::modpack::archive::{enclosed_path, extract_folder, read_entry};
+use crate::modpack::error::ModpackError;
+use crate::modpack::models::curseforge_file::{CurseForgeFile, CurseForgeFileResponse};
+use crate::modpack::models::curseforge_manifest::CurseForgeManifest;
+use crate::modpack::models::curseforge_project::{CurseForgeProject, CurseForgeProjectResponse};
+use crate::utils::DownloadTask;
+use crossbeam_channel::Sender;
+use serde::de::DeserializeOwned;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+const MANIFEST_FILE: &str = "manifest.json";
+const MANIFEST_TYPE: &str = "minecraftModpack";
+const MODS_FOLDER: &str = "mods";
+const RESOURCE_PACKS_FOLDER: &str = "resourcepacks";
+const SHADER_PACKS_FOLDER: &str = "shaderpacks";
+// Class ids of the Minecraft categories
+const MODS_CLASS: u32 = 6;
+const RESOURCE_PACKS_CLASS: u32 = 12;
+const SHADER_PACKS_CLASS: u32 = 6552;
+
+#[derive(Clone, Debug, Default)]
+pub struct CurseForgeReport {
+    pub unresolved: Vec<UnresolvedFile>,
+}
+
+// File of the manifest which could not be downloaded
+#[derive(Clone, Debug)]
+pub struct UnresolvedFile {
+    pub project_id: u32,
+    pub file_id: u32,
+    pub reason: String,
+}
+
+pub fn read_manifest(pack_path: impl AsRef<Path>) -> Result<CurseForgeManifest, ModpackError> {
+    debug!("Reading modpack manifest");
+    trace!("Modpack: {}", pack_path.as_ref().to_string_lossy());
+
+    let data = read_entry(&pack_path, MANIFEST_FILE)?;
+    let manifest =
+        serde_json::from_slice::<CurseForgeManifest>(&data).map_err(ModpackError::Serde)?;
+    if manifest.manifest_type != MANIFEST_TYPE {
+        return Err(ModpackError::UnsupportedManifest(manifest.manifest_type));
+    }
+    if manifest.manifest_version != 1 {
+        return Err(ModpackError::UnsupportedFormat(manifest.manifest_version));
+    }
+
+    Ok(manifest)
+}
+
+// Instance with the name, game version and loader of the modpack, paths still need to be set
+pub fn instance_builder(manifest: &CurseForgeManifest) -> Result<InstanceBuilder, ModpackError> {
+    let mut builder = InstanceBuilder::default();
+    builder
+        .name(manifest.name.clone())
+        .version(manifest.minecraft.version.clone());
+
+    if !manifest.version.is_empty() {
+        builder.description(format!("{} {}", &manifest.name, &manifest.version));
+    }
+    if let Some(loader) = manifest.loader()? {
+        builder.loader(loader);
+    }
+
+    Ok(builder)
+}
+
+pub fn resolve_file(
+    project_id: u32,
+    file_id: u32,
+    endpoints: &Endpoints,
+) -> Result<CurseForgeFile, ModpackError> {
+    let url = format!(
+        "{}/v1/mods/{}/files/{}",
+        endpoints.curseforge_api_url.trim_end_matches('/'),
+        project_id,
+        file_id
+    );
+    trace!("Resolving file: {}", &url);
+
+    Ok(get_json::<CurseForgeFileResponse>(&url, endpoints)?.data)
+}
+
+pub fn resolve_project(
+    project_id: u32,
+    endpoints: &Endpoints,
+) -> Result<CurseForgeProject, ModpackError> {
+    let url = format!(
+        "{}/v1/mods/{}",
+        endpoints.curseforge_api_url.trim_end_matches('/'),
+        project_id
+    );
+    trace!("Resolving project: {}", &url);
+
+    Ok(get_json::<CurseForgeProjectResponse>(&url, endpoints)?.data)
+}
+
+fn get_json<T: DeserializeOwned>(url: &str, endpoints: &Endpoints) -> Result<T, ModpackError> {
+    let mut request = reqwest::blocking::Client::new().get(url);
+    if let Some(api_key) = &endpoints.curseforge_api_key {
+        request = request.header("x-api-key", api_key);
+    }
+
+    let response = request
+        .send()
+        .and_then(|response| response.error_for_status())
+        .map_err(|err| ModpackError::Download(DownloadError::from_reqwest(url, err)))?;
+    let body = response
+        .bytes()
+        .map_err(|err| ModpackError::Download(DownloadError::from_reqwest(url, err)))?;
+
+    serde_json::from_slice::<T>(&body).map_err(ModpackError::Serde)
+}
+
+// Folder of the game directory the file belongs to. Jars are always mods, for other files
+// the class of the project decides. Returns None for classes which are not supported.
+fn files_folder(
+    project_id: u32,
+    file: &CurseForgeFile,
+    endpoints: &Endpoints,
+) -> Result<Option<&'static str>, ModpackError> {
+    if file.file_name.to_lowercase().ends_with(".jar") {
+        return Ok(Some(MODS_FOLDER));
+    }
+
+    let folder = match resolve_project(project_id, endpoints)?.class_id {
+        Some(MODS_CLASS) => Some(MODS_FOLDER),
+        Some(RESOURCE_PACKS_CLASS) => Some(RESOURCE_PACKS_FOLDER),
+        Some(SHADER_PACKS_CLASS) => Some(SHADER_PACKS_FOLDER),
+        _ => None,
+    };
+
+    Ok(folder)
+}
+
+// Files which can not be resolved are reported instead of failing the import.
+// Returns None if the installation got canceled.
+pub fn install_files(
+    manifest: &CurseForgeManifest,
+    minecraft_path: impl AsRef<Path>,
+    endpoints: &Endpoints,
+    download_options: &DownloadOptions,
+    update_sender: &Sender<InstallationUpdate>,
+    cancel: &AtomicBool,
+) -> Result<Option<CurseForgeReport>, ModpackError> {
+    debug!("Installing modpack files");
+
+    let files = manifest
+        .files
+        .iter()
+        .filter(|file| file.required)
+        .collect::<Vec<_>>();
+
+    let mut report = CurseForgeReport::default();
+    let mut tasks = vec![];
+
+    for (i, file) in files.iter().enumerate() {
+        if cancel.load(Ordering::Relaxed) {
+            let _ = update_sender.send(InstallationUpdate::Cancel);
+            return Ok(None);
+        }
+
+        let _ = update_sender.send(InstallationUpdate::Modpack(Progress {
+            total_files: files.len(),
+            current_file: i + 1,
+            ..Default::default()
+        }));
+
+        let unresolved = |reason: String| {
+            warn!(
+                "Could not resolve file {} of project {}: {}",
+                file.file_id, file.project_id, &reason
+            );
+            UnresolvedFile {
+                project_id: file.project_id,
+                file_id: file.file_id,
+                reason,
+            }
+        };
+
+        let resolved = match resolve_file(file.project_id, file.file_id, endpoints) {
+            Ok(resolved) => resolved,
+            Err(err) => {
+                report.unresolved.push(unresolved(err.to_string()));
+                continue;
+            }
+        };
+        let url = match &resolved.download_url {
+            Some(url) => url.clone(),
+            None => {
+                report.unresolved.push(unresolved(String::from(
+                    "Third party downloads are disabled",
+                )));
+                continue;
+            }
+        };
+        let folder = match files_folder(file.project_id, &resolved, endpoints) {
+            Ok(Some(folder)) => folder,
+            Ok(None) => {
+                report.unresolved.push(unresolved(format!(
+                    "Unsupported file type: {}",
+                    &resolved.file_name
+                )));
+                continue;
+            }
+            Err(err) => {
+                report.unresolved.push(unresolved(err.to_string()));
+                continue;
+            }
+        };
+        let dest = match enclosed_path(&minecraft_path, folder)
+            .and_then(|folder_path| enclosed_path(folder_path, &resolved.file_name))
+        {
+            Ok(dest) => dest,
+            Err(err) => {
+                report.unresolved.push(unresolved(err.to_string()));
+                continue;
+            }
+        };
+
+        let sha1 = match resolved.sha1().map(hex::decode).transpose() {
+            Ok(sha1) => sha1,
+            Err(err) => {
+                report
+                    .unresolved
+                    .push(unresolved(ModpackError::HashDecode(err).to_string()));
+                continue;
+            }
+        };
+
+        tasks.push(DownloadTask {
+            url,
+            dest,
+            sha1,
+            size: (resolved.file_length > 0).then_some(resolved.file_length),
+        });
+    }
+
+    if !download_all(
+        &tasks,
+        download_options,
+        InstallationUpdate::Modpack,
+        update_sender,
+        cancel,
+    )? {
+        return Ok(None);
+    }
+
+    Ok(Some(report))
+}
+
+// Copies the overrides folder declared by the manifest into `minecraft_path`
+pub fn extract_overrides(
+    manifest: &CurseForgeManifest,
+    pack_path: impl AsRef<Path>,
+    minecraft_path: impl AsRef<Path>,
+) -> Result<(), ModpackError> {
+    debug!("Extracting modpack overrides");
+
+    extract_folder(pack_path, &manifest.overrides, minecraft_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::EndpointsBuilder;
+    use crate::test_server;
+    use crate::test_server::Response;
+    use sha1::{Digest, Sha1};
+
+    const MOD_DATA: &[u8] = b"mod jar";
+    const PACK_DATA: &[u8] = b"resource pack zip";
+
+    // Stand-in for the CurseForge API and its CDN
+    fn serve() -> String {
+        test_server::serve(|request| {
+            let url = request.base_url.as_str();
+            let body = match request.path.as_str() {
+                "/v1/mods/1/files/10" => file_json(10, "example.jar", Some(url), MOD_DATA),
+                "/v1/mods/2/files/20" => file_json(20, "example.zip", Some(url), PACK_DATA),
+                "/v1/mods/3/files/30" => file_json(30, "hidden.jar", None, MOD_DATA),
+                "/v1/mods/6/files/60" => br#"{"data":{"id":60,"fileName":"broken.jar","downloadUrl":"http://invalid/broken.jar","hashes":[{"value":"not hex","algo":1}]}}"#.to_vec(),
+                "/v1/mods/2" => br#"{"data":{"id":2,"classId":12}}"#.to_vec(),
+                "/files/example.jar" => MOD_DATA.to_vec(),
+                "/files/example.zip" => PACK_DATA.to_vec(),
+                _ => return Response::not_found(),
+            };
+
+            Response::ok(body)
+        })
+    }
+
+    fn file_json(id: u32, file_name: &str, base_url: Option<&str>, data: &[u8]) -> Vec<u8> {
+        serde_json::json!({
+            "data": {
+                "id": id,
+                "fileName": file_name,
+                "downloadUrl": base_url.map(|url| format!("{}/files/{}", url, file_name)),
+                "hashes": [{ "value": hex::encode(Sha1::digest(data)), "algo": 1 }],
+                "fileLength": data.len(),
+            }
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    fn manifest() -> CurseForgeManifest {
+        serde_json::from_value(serde_json::json!({
+            "minecraft": { "version": "1.19.2", "modLoaders": [] },
+            "manifestType": MANIFEST_TYPE,
+            "manifestVersion": 1,
+            "name": "Example",
+            "files": [
+                { "projectID": 1, "fileID": 10, "required": true },
+                { "projectID": 2, "fileID": 20, "required": true },
+                { "projectID": 3, "fileID": 30, "required": true },
+                { "projectID": 4, "fileID": 40, "required": true },
+                { "projectID": 5, "fileID": 50, "required": false },
+                { "projectID": 6, "fileID": 60, "required": true },
+            ],
+        }))
+        .unwrap()
+    }
+
+    fn endpoints(base_url: &str) -> Endpoints {
+        EndpointsBuilder::default()
+            .curseforge_api_url(base_url.to_string())
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn resolve_file_from_stand_in() {
+        let endpoints = endpoints(&serve());
+
+        let file = resolve_file(1, 10, &endpoints).unwrap();
+        assert_eq!(file.id, 10);
+        assert_eq!(file.file_name, "example.jar");
+        assert_eq!(
+            file.sha1(),
+            Some(hex::encode(Sha1::digest(MOD_DATA)).as_str())
+        );
+
+        assert!(resolve_file(4, 40, &endpoints).is_err());
+    }
+
+    #[test]
+    fn install_files_from_stand_in() {
+        let endpoints = endpoints(&serve());
+        let minecraft_path =
+            std::env::temp_dir().join(format!("blocky-curseforge-{}", uuid::Uuid::new_v4()));
+        let (sender, _receiver) = crossbeam_channel::unbounded();
+
+        let report = install_files(
+            &manifest(),
+            &minecraft_path,
+            &endpoints,
+            &DownloadOptions::default(),
+            &sender,
+            &AtomicBool::new(false),
+        )
+        .unwrap()
+        .unwrap();
+
+        let mod_data = std::fs::read(minecraft_path.join("mods/example.jar")).unwrap();
+        let pack_data = std::fs::read(minecraft_path.join("resourcepacks/example.zip")).unwrap();
+        let mut unresolved = report
+            .unresolved
+            .iter()
+            .map(|file| file.project_id)
+            .collect::<Vec<_>>();
+        unresolved.sort_unstable();
+        let _ = std::fs::remove_dir_all(&minecraft_path);
+
+        assert_eq!(mod_data, MOD_DATA);
+        assert_eq!(pack_data, PACK_DATA);
+        assert_eq!(unresolved, vec![3, 4, 6]);
+    }
+
+    #[test]
+    fn install_files_canceled() {
+        let endpoints = endpoints(&serve());
+        let (sender, _receiver) = crossbeam_channel::unbounded();
+
+        let report = install_files(
+            &manifest(),
+            std::env::temp_dir(),
+            &endpoints,
+            &DownloadOptions::default(),
+            &sender,
+            &AtomicBool::new(true),
+        )
+        .unwrap();
+
+        assert!(report.is_none());
+    }
+}
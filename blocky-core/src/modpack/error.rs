@@ -0,0 +1,53 @@
+use crate::error::DownloadError;
+use crate::minecraft::error::MinecraftError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ModpackError {
+    #[error("Modpack format version {0} is not supported")]
+    UnsupportedFormat(u32),
+
+    #[error("Modpacks for '{0}' are not supported")]
+    UnsupportedGame(String),
+
+    #[error("Manifest type '{0}' is not supported")]
+    UnsupportedManifest(String),
+
+    #[error("Modpack does not declare a Minecraft version")]
+    MissingGameVersion,
+
+    #[error("Modpack depends on '{0}', which is not supported")]
+    UnsupportedDependency(String),
+
+    #[error("Modpack file '{0}' points outside of the instance")]
+    UnsafePath(String),
+
+    #[error("Modpack file '{0}' has no download")]
+    MissingDownload(String),
+
+    #[error("The checksum does not match hash of file '{0}'")]
+    Sha512Mismatch(String),
+
+    #[error("{0}")]
+    Minecraft(MinecraftError),
+
+    #[error("{0}")]
+    Download(DownloadError),
+
+    #[error("{0}")]
+    IO(std::io::Error),
+
+    #[error("{0}")]
+    Serde(serde_json::Error),
+
+    #[error("{0}")]
+    HashDecode(hex::FromHexError),
+
+    #[error("{0}")]
+    Extract(zip::result::ZipError),
+}
+
+impl From<MinecraftError> for ModpackError {
+    fn from(err: MinecraftError) -> Self {
+        Self::Minecraft(err)
+    }
+}
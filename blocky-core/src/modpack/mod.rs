@@ -0,0 +1,5 @@
+mod archive;
+pub mod curseforge;
+pub mod error;
+pub mod models;
+pub mod mrpack;
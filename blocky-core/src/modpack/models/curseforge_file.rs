@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+const SHA1_ALGO: u32 = 1;
+
+// Response of `/v1/mods/<project_id>/files/<file_id>`
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CurseForgeFileResponse {
+    pub data: CurseForgeFile,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CurseForgeFile {
+    pub id: u32,
+    pub file_name: String,
+    // Missing if the author does not allow third party downloads
+    pub download_url: Option<String>,
+    #[serde(default)]
+    pub hashes: Vec<CurseForgeFileHash>,
+    #[serde(default)]
+    pub file_length: usize,
+}
+
+impl CurseForgeFile {
+    pub fn sha1(&self) -> Option<&str> {
+        self.hashes
+            .iter()
+            .find(|hash| hash.algo == SHA1_ALGO)
+            .map(|hash| hash.value.as_str())
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CurseForgeFileHash {
+    pub value: String,
+    pub algo: u32,
+}
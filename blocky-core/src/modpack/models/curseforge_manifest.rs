@@ -0,0 +1,90 @@
+use crate::loader::models::loader::{Loader, LoaderKind};
+use crate::modpack::error::ModpackError;
+use serde::{Deserialize, Serialize};
+
+// `manifest.json` at the root of a CurseForge modpack zip
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CurseForgeManifest {
+    pub minecraft: CurseForgeMinecraft,
+    pub manifest_type: String,
+    pub manifest_version: u32,
+    pub name: String,
+    #[serde(default)]
+    pub version: String,
+    #[serde(default)]
+    pub author: String,
+    pub files: Vec<CurseForgeManifestFile>,
+    #[serde(default = "default_overrides")]
+    pub overrides: String,
+}
+
+impl CurseForgeManifest {
+    // Loader IDs look like `forge-43.1.1` or `fabric-0.14.9`
+    pub fn loader(&self) -> Result<Option<Loader>, ModpackError> {
+        let mod_loader = match self
+            .minecraft
+            .mod_loaders
+            .iter()
+            .find(|mod_loader| mod_loader.primary)
+            .or_else(|| self.minecraft.mod_loaders.first())
+        {
+            Some(mod_loader) => mod_loader,
+            None => return Ok(None),
+        };
+
+        let (kind, version) = mod_loader
+            .id
+            .split_once('-')
+            .ok_or_else(|| ModpackError::UnsupportedDependency(mod_loader.id.clone()))?;
+        let kind = match kind {
+            "fabric" => LoaderKind::Fabric,
+            "quilt" => LoaderKind::Quilt,
+            "forge" => LoaderKind::Forge,
+            "neoforge" => LoaderKind::NeoForge,
+            _ => return Err(ModpackError::UnsupportedDependency(mod_loader.id.clone())),
+        };
+
+        // Some loader versions repeat the game version, e.g. `neoforge-1.20.1-47.1.84`
+        let game_prefix = format!("{}-", &self.minecraft.version);
+        let version = version.strip_prefix(&game_prefix).unwrap_or(version);
+
+        Ok(Some(Loader {
+            kind,
+            version: version.to_string(),
+        }))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CurseForgeMinecraft {
+    pub version: String,
+    #[serde(default)]
+    pub mod_loaders: Vec<CurseForgeModLoader>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CurseForgeModLoader {
+    pub id: String,
+    #[serde(default)]
+    pub primary: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CurseForgeManifestFile {
+    #[serde(rename = "projectID")]
+    pub project_id: u32,
+    #[serde(rename = "fileID")]
+    pub file_id: u32,
+    #[serde(default = "default_required")]
+    pub required: bool,
+}
+
+fn default_overrides() -> String {
+    String::from("overrides")
+}
+
+fn default_required() -> bool {
+    true
+}
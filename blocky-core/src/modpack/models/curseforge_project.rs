@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+// Response of `/v1/mods/<project_id>`
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CurseForgeProjectResponse {
+    pub data: CurseForgeProject,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CurseForgeProject {
+    pub id: u32,
+    // Category class like mods, resource packs or shader packs
+    pub class_id: Option<u32>,
+}
@@ -0,0 +1,4 @@
+pub mod curseforge_file;
+pub mod curseforge_manifest;
+pub mod curseforge_project;
+pub mod mrpack_index;
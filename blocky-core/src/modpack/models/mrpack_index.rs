@@ -0,0 +1,83 @@
+use crate::loader::models::loader::{Loader, LoaderKind};
+use crate::modpack::error::ModpackError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// `modrinth.index.json` at the root of a `.mrpack`
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MrpackIndex {
+    pub format_version: u32,
+    pub game: String,
+    pub version_id: String,
+    pub name: String,
+    pub summary: Option<String>,
+    pub files: Vec<MrpackFile>,
+    // `minecraft`, `forge`, `neoforge`, `fabric-loader` or `quilt-loader` to their version
+    pub dependencies: HashMap<String, String>,
+}
+
+impl MrpackIndex {
+    pub fn game_version(&self) -> Result<&str, ModpackError> {
+        self.dependencies
+            .get("minecraft")
+            .map(String::as_str)
+            .ok_or(ModpackError::MissingGameVersion)
+    }
+
+    pub fn loader(&self) -> Result<Option<Loader>, ModpackError> {
+        let mut loader = None;
+
+        for (dependency, version) in &self.dependencies {
+            let kind = match dependency.as_str() {
+                "minecraft" => continue,
+                "fabric-loader" => LoaderKind::Fabric,
+                "quilt-loader" => LoaderKind::Quilt,
+                "forge" => LoaderKind::Forge,
+                "neoforge" => LoaderKind::NeoForge,
+                _ => return Err(ModpackError::UnsupportedDependency(dependency.clone())),
+            };
+
+            loader = Some(Loader {
+                kind,
+                version: version.clone(),
+            });
+        }
+
+        Ok(loader)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MrpackFile {
+    pub path: String,
+    pub hashes: HashMap<String, String>,
+    pub env: Option<MrpackEnv>,
+    pub downloads: Vec<String>,
+    pub file_size: usize,
+}
+
+impl MrpackFile {
+    // Files without `env` are needed on both sides
+    pub fn is_client_file(&self) -> bool {
+        self.env
+            .as_ref()
+            .map(|env| env.client != MrpackSupport::Unsupported)
+            .unwrap_or(true)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MrpackEnv {
+    pub client: MrpackSupport,
+    pub server: MrpackSupport,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MrpackSupport {
+    Required,
+    Optional,
+    Unsupported,
+}
@@ -0,0 +1,125 @@
+use crate::instance::InstanceBuilder;
+use crate::minecraft::download_options::DownloadOptions;
+use crate::minecraft::install::download_all;
+use crate::minecraft::installation_update::InstallationUpdate;
+use crate::modpack::archive::{enclosed_path, extract_folder, read_entry};
+use crate::modpack::error::ModpackError;
+use crate::modpack::models::mrpack_index::MrpackIndex;
+use crate::utils::{get_sha512, DownloadTask};
+use crossbeam_channel::Sender;
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+
+const INDEX_FILE: &str = "modrinth.index.json";
+// Client overrides are applied last and replace files of the common overrides
+const OVERRIDE_FOLDERS: [&str; 2] = ["overrides", "client-overrides"];
+
+pub fn read_index(pack_path: impl AsRef<Path>) -> Result<MrpackIndex, ModpackError> {
+    debug!("Reading modpack index");
+    trace!("Modpack: {}", pack_path.as_ref().to_string_lossy());
+
+    let data = read_entry(&pack_path, INDEX_FILE)?;
+    let index = serde_json::from_slice::<MrpackIndex>(&data).map_err(ModpackError::Serde)?;
+    if index.format_version != 1 {
+        return Err(ModpackError::UnsupportedFormat(index.format_version));
+    }
+    if index.game != "minecraft" {
+        return Err(ModpackError::UnsupportedGame(index.game));
+    }
+
+    Ok(index)
+}
+
+// Instance with the name, game version and loader of the modpack, paths still need to be set
+pub fn instance_builder(index: &MrpackIndex) -> Result<InstanceBuilder, ModpackError> {
+    let mut builder = InstanceBuilder::default();
+    builder
+        .name(index.name.clone())
+        .version(index.game_version()?.to_string());
+
+    if let Some(summary) = &index.summary {
+        builder.description(summary.clone());
+    }
+    if let Some(loader) = index.loader()? {
+        builder.loader(loader);
+    }
+
+    Ok(builder)
+}
+
+// Returns false if the installation got canceled
+pub fn install_files(
+    index: &MrpackIndex,
+    minecraft_path: impl AsRef<Path>,
+    download_options: &DownloadOptions,
+    update_sender: &Sender<InstallationUpdate>,
+    cancel: &AtomicBool,
+) -> Result<bool, ModpackError> {
+    debug!("Installing modpack files");
+
+    let mut tasks = vec![];
+    let mut sha512s = vec![];
+
+    for file in index.files.iter().filter(|file| file.is_client_file()) {
+        trace!("Modpack file: {}", &file.path);
+        let dest = enclosed_path(&minecraft_path, &file.path)?;
+        let url = file
+            .downloads
+            .first()
+            .ok_or_else(|| ModpackError::MissingDownload(file.path.clone()))?;
+
+        let sha1 = match file.hashes.get("sha1") {
+            Some(sha1) => Some(hex::decode(sha1).map_err(ModpackError::HashDecode)?),
+            None => None,
+        };
+        if let Some(sha512) = file.hashes.get("sha512") {
+            sha512s.push((
+                dest.clone(),
+                hex::decode(sha512).map_err(ModpackError::HashDecode)?,
+            ));
+        }
+
+        tasks.push(DownloadTask {
+            url: url.clone(),
+            dest,
+            sha1,
+            size: Some(file.file_size),
+        });
+    }
+
+    if !download_all(
+        &tasks,
+        download_options,
+        InstallationUpdate::Modpack,
+        update_sender,
+        cancel,
+    )? {
+        return Ok(false);
+    }
+
+    for (path, sha512) in sha512s {
+        if get_sha512(&path).map_err(ModpackError::Download)? != sha512 {
+            fs::remove_file(&path).map_err(ModpackError::IO)?;
+            return Err(ModpackError::Sha512Mismatch(
+                path.to_string_lossy().to_string(),
+            ));
+        }
+    }
+
+    Ok(true)
+}
+
+// Copies `overrides` and `client-overrides` into `minecraft_path`
+pub fn extract_overrides(
+    pack_path: impl AsRef<Path>,
+    minecraft_path: impl AsRef<Path>,
+) -> Result<(), ModpackError> {
+    debug!("Extracting modpack overrides");
+
+    for folder in OVERRIDE_FOLDERS {
+        extract_folder(&pack_path, folder, &minecraft_path)?;
+    }
+
+    Ok(())
+}
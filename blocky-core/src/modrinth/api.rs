@@ -0,0 +1,111 @@
+use crate::consts;
+use crate::endpoints::Endpoints;
+use crate::error::DownloadError;
+use crate::modrinth::error::ModrinthError;
+use crate::modrinth::models::mod_filter::ModFilter;
+use crate::modrinth::models::project::{Project, SearchResponse};
+use crate::modrinth::models::version::Version;
+use reqwest::blocking::RequestBuilder;
+use serde::de::DeserializeOwned;
+use serde_json::json;
+use std::collections::HashMap;
+
+pub fn search(
+    query: &str,
+    filter: &ModFilter,
+    offset: usize,
+    limit: usize,
+    endpoints: &Endpoints,
+) -> Result<SearchResponse, ModrinthError> {
+    debug!("Searching Modrinth projects");
+    trace!("Query: {}", query);
+
+    let url = api_url(endpoints, "search");
+    let request = client_get(&url).query(&[
+        ("query", query.to_string()),
+        ("facets", filter.facets()),
+        ("offset", offset.to_string()),
+        ("limit", limit.to_string()),
+    ]);
+
+    send(&url, request)
+}
+
+pub fn project(id: &str, endpoints: &Endpoints) -> Result<Project, ModrinthError> {
+    debug!("Getting Modrinth project '{}'", id);
+
+    let url = api_url(endpoints, &format!("project/{}", id));
+    send(&url, client_get(&url))
+}
+
+// Versions of a project matching `filter`, newest first
+pub fn project_versions(
+    id: &str,
+    filter: &ModFilter,
+    endpoints: &Endpoints,
+) -> Result<Vec<Version>, ModrinthError> {
+    debug!("Getting versions of Modrinth project '{}'", id);
+
+    let url = api_url(endpoints, &format!("project/{}/version", id));
+    let mut request =
+        client_get(&url).query(&[("game_versions", json!([&filter.game_version]).to_string())]);
+    if !filter.loaders.is_empty() {
+        request = request.query(&[("loaders", json!(&filter.loaders).to_string())]);
+    }
+
+    send(&url, request)
+}
+
+pub fn version(id: &str, endpoints: &Endpoints) -> Result<Version, ModrinthError> {
+    debug!("Getting Modrinth version '{}'", id);
+
+    let url = api_url(endpoints, &format!("version/{}", id));
+    send(&url, client_get(&url))
+}
+
+// Versions which contain the files with the given SHA1 hashes, keyed by hash
+pub fn versions_from_hashes(
+    hashes: &[String],
+    endpoints: &Endpoints,
+) -> Result<HashMap<String, Version>, ModrinthError> {
+    debug!("Identifying {} files on Modrinth", hashes.len());
+
+    if hashes.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let url = api_url(endpoints, "version_files");
+    let request = reqwest::blocking::Client::new()
+        .post(&url)
+        .header(reqwest::header::USER_AGENT, consts::MODRINTH_USER_AGENT)
+        .json(&json!({ "hashes": hashes, "algorithm": "sha1" }));
+
+    send(&url, request)
+}
+
+fn api_url(endpoints: &Endpoints, path: &str) -> String {
+    format!(
+        "{}/{}",
+        endpoints.modrinth_api_url.trim_end_matches('/'),
+        path
+    )
+}
+
+// Modrinth asks clients to identify themselves
+fn client_get(url: &str) -> RequestBuilder {
+    reqwest::blocking::Client::new()
+        .get(url)
+        .header(reqwest::header::USER_AGENT, consts::MODRINTH_USER_AGENT)
+}
+
+fn send<T: DeserializeOwned>(url: &str, request: RequestBuilder) -> Result<T, ModrinthError> {
+    let response = request
+        .send()
+        .and_then(|response| response.error_for_status())
+        .map_err(|err| ModrinthError::Download(DownloadError::from_reqwest(url, err)))?;
+    let body = response
+        .bytes()
+        .map_err(|err| ModrinthError::Download(DownloadError::from_reqwest(url, err)))?;
+
+    serde_json::from_slice::<T>(&body).map_err(ModrinthError::Serde)
+}
@@ -0,0 +1,25 @@
+use crate::error::DownloadError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ModrinthError {
+    #[error("Project '{0}' has no version for this instance")]
+    NoCompatibleVersion(String),
+
+    #[error("Version '{0}' has no files")]
+    NoFile(String),
+
+    #[error("'{0}' is not a valid file name")]
+    InvalidFileName(String),
+
+    #[error("{0}")]
+    Download(DownloadError),
+
+    #[error("{0}")]
+    IO(std::io::Error),
+
+    #[error("{0}")]
+    Serde(serde_json::Error),
+
+    #[error("{0}")]
+    Sha1Decode(hex::FromHexError),
+}
@@ -0,0 +1,10 @@
+use crate::modrinth::models::version::Version;
+use serde::{Deserialize, Serialize};
+
+// File in the `mods` folder of an instance
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct InstalledMod {
+    pub file_name: String,
+    // Only known for files published on Modrinth
+    pub version: Option<Version>,
+}
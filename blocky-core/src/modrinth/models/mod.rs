@@ -0,0 +1,4 @@
+pub mod installed_mod;
+pub mod mod_filter;
+pub mod project;
+pub mod version;
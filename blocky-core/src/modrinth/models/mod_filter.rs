@@ -0,0 +1,53 @@
+use crate::loader::models::loader::{Loader, LoaderKind};
+use crate::modrinth::models::version::Version;
+use serde::{Deserialize, Serialize};
+
+// Restricts projects and versions to a game version and the loaders able to run them
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ModFilter {
+    pub game_version: String,
+    pub loaders: Vec<String>,
+}
+
+impl ModFilter {
+    pub fn new(game_version: &str, loader: Option<&Loader>) -> Self {
+        let loaders = match loader.map(|loader| loader.kind) {
+            None => vec![],
+            // Quilt is able to load most Fabric mods
+            Some(LoaderKind::Quilt) => vec![LoaderKind::Quilt, LoaderKind::Fabric],
+            Some(kind) => vec![kind],
+        };
+
+        Self {
+            game_version: game_version.to_string(),
+            loaders: loaders.iter().map(LoaderKind::to_string).collect(),
+        }
+    }
+
+    pub fn matches(&self, version: &Version) -> bool {
+        version.game_versions.contains(&self.game_version)
+            && (self.loaders.is_empty()
+                || version
+                    .loaders
+                    .iter()
+                    .any(|loader| self.loaders.contains(loader)))
+    }
+
+    // Facets of `/search`, e.g. `[["project_type:mod"],["versions:1.19.2"],["categories:fabric"]]`
+    pub fn facets(&self) -> String {
+        let mut facets = vec![
+            vec![String::from("project_type:mod")],
+            vec![format!("versions:{}", &self.game_version)],
+        ];
+        if !self.loaders.is_empty() {
+            facets.push(
+                self.loaders
+                    .iter()
+                    .map(|loader| format!("categories:{}", loader))
+                    .collect(),
+            );
+        }
+
+        serde_json::to_string(&facets).unwrap_or_default()
+    }
+}
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+// Response of `/search`
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchResponse {
+    pub hits: Vec<SearchHit>,
+    pub offset: usize,
+    pub limit: usize,
+    pub total_hits: usize,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SearchHit {
+    pub project_id: String,
+    pub slug: Option<String>,
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub author: String,
+    #[serde(default)]
+    pub downloads: u64,
+    pub icon_url: Option<String>,
+}
+
+// Response of `/project/<id>`
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Project {
+    pub id: String,
+    pub slug: String,
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    pub project_type: String,
+    pub client_side: String,
+    pub server_side: String,
+    #[serde(default)]
+    pub versions: Vec<String>,
+}
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+// Response of `/version/<id>`
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Version {
+    pub id: String,
+    pub project_id: String,
+    pub name: String,
+    pub version_number: String,
+    #[serde(default)]
+    pub game_versions: Vec<String>,
+    #[serde(default)]
+    pub loaders: Vec<String>,
+    pub files: Vec<VersionFile>,
+    #[serde(default)]
+    pub dependencies: Vec<Dependency>,
+}
+
+impl Version {
+    // The primary file, or the first one if none is marked
+    pub fn primary_file(&self) -> Option<&VersionFile> {
+        self.files
+            .iter()
+            .find(|file| file.primary)
+            .or_else(|| self.files.first())
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct VersionFile {
+    pub hashes: VersionFileHashes,
+    pub url: String,
+    pub filename: String,
+    #[serde(default)]
+    pub primary: bool,
+    #[serde(default)]
+    pub size: usize,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct VersionFileHashes {
+    pub sha1: String,
+    pub sha512: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Dependency {
+    pub version_id: Option<String>,
+    pub project_id: Option<String>,
+    pub file_name: Option<String>,
+    pub dependency_type: DependencyType,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DependencyType {
+    Required,
+    Optional,
+    Incompatible,
+    Embedded,
+}
@@ -0,0 +1,110 @@
+use crate::endpoints::Endpoints;
+use crate::modrinth::api;
+use crate::modrinth::error::ModrinthError;
+use crate::modrinth::models::mod_filter::ModFilter;
+use crate::modrinth::models::version::{DependencyType, Version};
+use std::collections::{HashSet, VecDeque};
+
+#[derive(Clone, Debug, Default)]
+pub struct Resolution {
+    // The requested version first, followed by its required dependencies
+    pub versions: Vec<Version>,
+    // Incompatibilities and dependencies without a matching version
+    pub warnings: Vec<String>,
+    // Files of other versions of the same projects, removed once `versions` are installed
+    pub superseded: Vec<String>,
+}
+
+// Collects the required dependencies of `version` which are not installed yet
+pub fn resolve(
+    version: Version,
+    installed: &[Version],
+    filter: &ModFilter,
+    endpoints: &Endpoints,
+) -> Result<Resolution, ModrinthError> {
+    debug!("Resolving dependencies of '{}'", &version.name);
+
+    let mut resolution = Resolution::default();
+    let mut projects = installed
+        .iter()
+        .filter(|installed| installed.project_id != version.project_id)
+        .map(|installed| installed.project_id.clone())
+        .collect::<HashSet<_>>();
+    projects.insert(version.project_id.clone());
+
+    let mut queue = VecDeque::from([version]);
+    while let Some(version) = queue.pop_front() {
+        for dependency in &version.dependencies {
+            if dependency.dependency_type != DependencyType::Required {
+                continue;
+            }
+            if matches!(&dependency.project_id, Some(project_id) if projects.contains(project_id)) {
+                continue;
+            }
+
+            let resolved = match (&dependency.version_id, &dependency.project_id) {
+                (Some(version_id), _) => Some(api::version(version_id, endpoints)?),
+                (None, Some(project_id)) => api::project_versions(project_id, filter, endpoints)?
+                    .into_iter()
+                    .next(),
+                (None, None) => continue,
+            };
+
+            match resolved {
+                Some(resolved) if projects.insert(resolved.project_id.clone()) => {
+                    trace!("Dependency: {}", &resolved.name);
+                    // Pinned versions are installed as declared, even for another game version
+                    if !filter.matches(&resolved) {
+                        resolution.warnings.push(format!(
+                            "'{}' requires '{}', which is not made for this instance",
+                            &version.name, &resolved.name
+                        ));
+                    }
+                    queue.push_back(resolved);
+                }
+                Some(_) => {}
+                None => resolution.warnings.push(format!(
+                    "'{}' requires '{}', which has no version for this instance",
+                    &version.name,
+                    dependency.project_id.as_deref().unwrap_or_default()
+                )),
+            }
+        }
+
+        resolution.versions.push(version);
+    }
+
+    // Incompatibilities are declared by either side
+    let installed = installed
+        .iter()
+        .filter(|installed| !projects_of(&resolution.versions).contains(&installed.project_id));
+    for (version, other) in resolution
+        .versions
+        .iter()
+        .flat_map(|version| installed.clone().map(move |other| (version, other)))
+    {
+        if declares_incompatible(version, other) || declares_incompatible(other, version) {
+            resolution.warnings.push(format!(
+                "'{}' is incompatible with '{}'",
+                &version.name, &other.name
+            ));
+        }
+    }
+
+    Ok(resolution)
+}
+
+fn projects_of(versions: &[Version]) -> HashSet<String> {
+    versions
+        .iter()
+        .map(|version| version.project_id.clone())
+        .collect()
+}
+
+fn declares_incompatible(version: &Version, other: &Version) -> bool {
+    version.dependencies.iter().any(|dependency| {
+        dependency.dependency_type == DependencyType::Incompatible
+            && (dependency.project_id.as_ref() == Some(&other.project_id)
+                || dependency.version_id.as_ref() == Some(&other.id))
+    })
+}
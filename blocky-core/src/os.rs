@@ -1,4 +1,9 @@
 use serde::{Deserialize, Serialize};
+use std::fs;
+use std::process::Command;
+use std::sync::Mutex;
+
+static OS_VERSION: Mutex<Option<String>> = Mutex::new(None);
 
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub enum Platform {
@@ -33,27 +38,101 @@ impl Platform {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub enum Architecture {
     #[serde(alias = "x86")]
     I386,
     #[serde(alias = "x86_64")]
     AMD64,
+    #[serde(alias = "arm64", alias = "aarch64")]
+    ARM64,
+    #[serde(alias = "arm32", alias = "arm")]
+    ARM32,
+    // Neither rules nor native classifiers match it, e.g. riscv64 or ppc64le
+    Other,
 }
 
 impl Architecture {
     pub fn current() -> Self {
-        if cfg!(target_pointer_width = "32") {
-            Self::I386
-        } else {
-            Self::AMD64
+        match std::env::consts::ARCH {
+            "x86" => Self::I386,
+            "aarch64" => Self::ARM64,
+            "arm" => Self::ARM32,
+            "x86_64" => Self::AMD64,
+            _ => Self::Other,
+        }
+    }
+
+    // Names used in rules and native classifiers, e.g. `natives-linux-arm64`
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "x86" | "i386" => Some(Self::I386),
+            "x86_64" | "amd64" => Some(Self::AMD64),
+            "arm64" | "aarch64" => Some(Self::ARM64),
+            "arm32" | "arm" => Some(Self::ARM32),
+            _ => None,
+        }
+    }
+
+    pub fn matches_current(&self) -> bool {
+        self != &Self::Other && self == &Self::current()
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Architecture::I386 => "x86",
+            Architecture::AMD64 => "x86_64",
+            Architecture::ARM64 => "arm64",
+            Architecture::ARM32 => "arm32",
+            Architecture::Other => "other",
         }
     }
 
     pub fn get_bits(&self) -> u8 {
         match self {
-            Architecture::I386 => 32,
-            Architecture::AMD64 => 64,
+            Architecture::I386 | Architecture::ARM32 => 32,
+            Architecture::AMD64 | Architecture::ARM64 => 64,
+            Architecture::Other if cfg!(target_pointer_width = "32") => 32,
+            Architecture::Other => 64,
         }
     }
 }
+
+// Same value Java reports as `os.version`, which is what the `version` regex of rules is
+// matched against
+pub fn os_version() -> Option<String> {
+    let mut cached = OS_VERSION.lock().unwrap();
+    if cached.is_none() {
+        *cached = read_os_version();
+    }
+
+    cached.clone()
+}
+
+fn read_os_version() -> Option<String> {
+    match Platform::current() {
+        Platform::Linux => fs::read_to_string("/proc/sys/kernel/osrelease")
+            .ok()
+            .map(|version| version.trim().to_string()),
+        Platform::MacOs => command_output(Command::new("sw_vers").arg("-productVersion")),
+        // `Microsoft Windows [Version 10.0.19044.1706]`
+        Platform::Windows => {
+            command_output(Command::new("cmd").args(["/C", "ver"])).and_then(|output| {
+                let version = output.rsplit(' ').next()?.trim_end_matches(']');
+                let mut parts = version.split('.');
+                Some(format!("{}.{}", parts.next()?, parts.next()?))
+            })
+        }
+        Platform::Other => None,
+    }
+}
+
+fn command_output(command: &mut Command) -> Option<String> {
+    let output = command.output().ok()?;
+    let output = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+    match output.is_empty() {
+        true => None,
+        false => Some(output),
+    }
+}
@@ -5,7 +5,7 @@ use super::minecraft::{MinecraftProfile, MinecraftToken};
 use super::xbox_live::XboxLiveToken;
 use super::xbox_live_security::XboxLiveSecurityToken;
 use super::Profile;
-use crate::consts;
+use crate::endpoints::Endpoints;
 use crate::error;
 use oauth2::basic::BasicClient;
 use oauth2::reqwest::http_client;
@@ -19,7 +19,11 @@ use std::net::TcpListener;
 use uuid::Uuid;
 
 impl Profile {
-    pub fn authenticate_microsoft(client_id: &str, client_secret: &str) -> error::Result<Self> {
+    pub fn authenticate_microsoft(
+        client_id: &str,
+        client_secret: &str,
+        endpoints: &Endpoints,
+    ) -> error::Result<Self> {
         debug!("Authenticate with Microsoft");
 
         trace!("Setting up TCP listener");
@@ -33,8 +37,8 @@ impl Profile {
         trace!("Setting up OAuth client");
         let client_id = ClientId::new(client_id.to_string());
         let client_secret = ClientSecret::new(client_secret.to_string());
-        let auth_url = AuthUrl::new(consts::MS_AUTH_CODE_URL.to_string()).unwrap();
-        let token_url = TokenUrl::new(consts::MS_AUTH_TOKEN_URL.to_string()).unwrap();
+        let auth_url = AuthUrl::new(endpoints.ms_auth_code_url.clone()).unwrap();
+        let token_url = TokenUrl::new(endpoints.ms_auth_token_url.clone()).unwrap();
         let redirect_url =
             RedirectUrl::new(format!("http://localhost:{}/blocky_auth", listen_port)).unwrap();
 
@@ -82,18 +86,18 @@ impl Profile {
         Ok(profile)
     }
 
-    pub fn authenticate_xbox_live(&mut self) -> error::Result<()> {
+    pub fn authenticate_xbox_live(&mut self, endpoints: &Endpoints) -> error::Result<()> {
         debug!("Authenticate with XBox Live");
 
         self.microsoft.check_expired()?;
 
-        let token = XboxLiveToken::authenticate(&self.microsoft.token)?;
+        let token = XboxLiveToken::authenticate(&self.microsoft.token, endpoints)?;
         self.xbox_live = Some(token);
 
         Ok(())
     }
 
-    pub fn authenticate_xbox_live_security(&mut self) -> error::Result<()> {
+    pub fn authenticate_xbox_live_security(&mut self, endpoints: &Endpoints) -> error::Result<()> {
         debug!("Authenticate with XBox Live Security");
 
         match &self.xbox_live {
@@ -101,7 +105,7 @@ impl Profile {
             Some(xbox_live) => {
                 xbox_live.check_expired()?;
 
-                let token = XboxLiveSecurityToken::authenticate(&xbox_live.token)?;
+                let token = XboxLiveSecurityToken::authenticate(&xbox_live.token, endpoints)?;
                 self.xbox_live_security = Some(token);
 
                 Ok(())
@@ -109,7 +113,7 @@ impl Profile {
         }
     }
 
-    pub fn authenticate_minecraft(&mut self) -> error::Result<()> {
+    pub fn authenticate_minecraft(&mut self, endpoints: &Endpoints) -> error::Result<()> {
         debug!("Authenticate with Minecraft");
 
         match &self.xbox_live_security {
@@ -122,7 +126,8 @@ impl Profile {
                     .as_ref()
                     .ok_or(AuthenticationError::UserHash)?;
 
-                let token = MinecraftToken::authenticate(&xbox_live_security.token, user_hash)?;
+                let token =
+                    MinecraftToken::authenticate(&xbox_live_security.token, user_hash, endpoints)?;
                 self.minecraft = Some(token);
 
                 Ok(())
@@ -130,14 +135,19 @@ impl Profile {
         }
     }
 
-    pub fn refresh(&mut self, client_id: &str, client_secret: &str) -> error::Result<()> {
+    pub fn refresh(
+        &mut self,
+        client_id: &str,
+        client_secret: &str,
+        endpoints: &Endpoints,
+    ) -> error::Result<()> {
         debug!("Refreshing tokens");
 
         if let Some(refresh_token) = &self.microsoft.refresh_token {
             let client_id = ClientId::new(client_id.to_string());
             let client_secret = ClientSecret::new(client_secret.to_string());
-            let auth_url = AuthUrl::new(consts::MS_AUTH_CODE_URL.to_string()).unwrap();
-            let token_url = TokenUrl::new(consts::MS_AUTH_TOKEN_URL.to_string()).unwrap();
+            let auth_url = AuthUrl::new(endpoints.ms_auth_code_url.clone()).unwrap();
+            let token_url = TokenUrl::new(endpoints.ms_auth_token_url.clone()).unwrap();
 
             let oauth_client =
                 BasicClient::new(client_id, Some(client_secret), auth_url, Some(token_url))
@@ -150,15 +160,15 @@ impl Profile {
                 .map_err(|_| AuthenticationError::CodeExchange)?;
 
             self.microsoft = MicrosoftToken::from_token_response(token_response);
-            self.authenticate_xbox_live()?;
-            self.authenticate_xbox_live_security()?;
-            self.authenticate_minecraft()?;
+            self.authenticate_xbox_live(endpoints)?;
+            self.authenticate_xbox_live_security(endpoints)?;
+            self.authenticate_minecraft(endpoints)?;
         }
 
         Ok(())
     }
 
-    pub fn set_entitlements(&mut self) -> error::Result<()> {
+    pub fn set_entitlements(&mut self, endpoints: &Endpoints) -> error::Result<()> {
         debug!("Get Minecraft entitlements");
 
         match &self.minecraft {
@@ -166,7 +176,7 @@ impl Profile {
             Some(minecraft) => {
                 minecraft.check_expired()?;
 
-                let entitlements = Entitlements::get_entitlements(&minecraft.token)?;
+                let entitlements = Entitlements::get_entitlements(&minecraft.token, endpoints)?;
                 self.entitlements = Some(entitlements);
 
                 Ok(())
@@ -174,7 +184,7 @@ impl Profile {
         }
     }
 
-    pub fn set_profile(&mut self) -> error::Result<()> {
+    pub fn set_profile(&mut self, endpoints: &Endpoints) -> error::Result<()> {
         debug!("Get Minecraft profile");
 
         match &self.minecraft {
@@ -194,7 +204,7 @@ impl Profile {
                     }
                 }
 
-                let profile = MinecraftProfile::get_profile(&minecraft.token)?;
+                let profile = MinecraftProfile::get_profile(&minecraft.token, endpoints)?;
                 self.minecraft_profile = Some(profile);
 
                 Ok(())
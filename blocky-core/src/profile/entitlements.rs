@@ -1,5 +1,5 @@
 use super::error::AuthenticationError;
-use crate::consts;
+use crate::endpoints::Endpoints;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -10,10 +10,13 @@ pub struct Entitlements {
 }
 
 impl Entitlements {
-    pub fn get_entitlements(minecraft_token: &str) -> Result<Self, AuthenticationError> {
+    pub fn get_entitlements(
+        minecraft_token: &str,
+        endpoints: &Endpoints,
+    ) -> Result<Self, AuthenticationError> {
         let http_client = reqwest::blocking::Client::new();
         let response = http_client
-            .get(consts::MC_ENTITLEMENTS_URL)
+            .get(&endpoints.mc_entitlements_url)
             .bearer_auth(minecraft_token)
             .send()?
             .error_for_status()?
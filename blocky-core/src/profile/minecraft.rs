@@ -1,5 +1,5 @@
 use super::error::{AuthenticationError, TokenKind};
-use crate::consts;
+use crate::endpoints::Endpoints;
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -13,10 +13,13 @@ pub struct MinecraftProfile {
 }
 
 impl MinecraftProfile {
-    pub fn get_profile(minecraft_token: &str) -> Result<Self, AuthenticationError> {
+    pub fn get_profile(
+        minecraft_token: &str,
+        endpoints: &Endpoints,
+    ) -> Result<Self, AuthenticationError> {
         let http_client = reqwest::blocking::Client::new();
         let response = http_client
-            .get(consts::MC_PROFILE_URL)
+            .get(&endpoints.mc_profile_url)
             .bearer_auth(minecraft_token)
             .send()?
             .error_for_status()?
@@ -37,10 +40,11 @@ impl MinecraftToken {
     pub fn authenticate(
         xbox_live_security_token: &str,
         user_hash: &str,
+        endpoints: &Endpoints,
     ) -> Result<Self, AuthenticationError> {
         let http_client = reqwest::blocking::Client::new();
         let response = http_client
-            .post(consts::MC_AUTH_TOKEN_URL)
+            .post(&endpoints.mc_auth_token_url)
             .json(&json!({
                 "identityToken": format!("XBL3.0 x={};{}", user_hash, xbox_live_security_token)
             }))
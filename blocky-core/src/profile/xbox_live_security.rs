@@ -1,5 +1,5 @@
 use super::error::{AuthenticationError, TokenKind};
-use crate::consts;
+use crate::endpoints::Endpoints;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -12,10 +12,13 @@ pub struct XboxLiveSecurityToken {
 }
 
 impl XboxLiveSecurityToken {
-    pub fn authenticate(xbox_live_token: &str) -> Result<Self, AuthenticationError> {
+    pub fn authenticate(
+        xbox_live_token: &str,
+        endpoints: &Endpoints,
+    ) -> Result<Self, AuthenticationError> {
         let http_client = reqwest::blocking::Client::new();
         let response = http_client
-            .post(consts::XSTS_AUTH_TOKEN_URL)
+            .post(&endpoints.xsts_auth_token_url)
             .json(&json!({
                 "Properties": {
                     "SandboxId": "RETAIL",
@@ -0,0 +1,68 @@
+use crate::error::DownloadError;
+use crate::utils::{get_sha1, link_or_copy};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Files are stored by their SHA1 and hardlinked into the instance directories
+
+pub(crate) fn store_file_path(store_path: impl AsRef<Path>, sha1: &[u8]) -> PathBuf {
+    let hash = hex::encode(sha1);
+
+    let mut path = PathBuf::from(store_path.as_ref());
+    path.push(&hash[..2]);
+    path.push(&hash);
+    path
+}
+
+// Returns true if the file was found in the store
+pub(crate) fn fetch(
+    store_path: impl AsRef<Path>,
+    sha1: &[u8],
+    dest: impl AsRef<Path>,
+) -> Result<bool, DownloadError> {
+    let store_file_path = store_file_path(store_path, sha1);
+    if !store_file_path.is_file() {
+        return Ok(false);
+    }
+
+    // Stored files could have been modified through one of their links
+    if get_sha1(&store_file_path)? != sha1 {
+        debug!(
+            "Removing corrupt file from store: {}",
+            store_file_path.to_string_lossy()
+        );
+        fs::remove_file(&store_file_path).map_err(DownloadError::IO)?;
+        return Ok(false);
+    }
+
+    trace!(
+        "Using file from store: {}",
+        store_file_path.to_string_lossy()
+    );
+    link_or_copy(&store_file_path, &dest)?;
+
+    Ok(true)
+}
+
+pub(crate) fn insert(
+    store_path: impl AsRef<Path>,
+    sha1: &[u8],
+    file: impl AsRef<Path>,
+) -> Result<(), DownloadError> {
+    // Concurrent inserts of the same file are fine, the last rename wins
+    let store_file_path = store_file_path(store_path, sha1);
+    if store_file_path.exists() {
+        return Ok(());
+    }
+
+    trace!(
+        "Adding file to store: {}",
+        store_file_path.to_string_lossy()
+    );
+    if let Some(parent) = store_file_path.parent() {
+        fs::create_dir_all(parent).map_err(DownloadError::IO)?;
+    }
+    link_or_copy(file, &store_file_path)?;
+
+    Ok(())
+}
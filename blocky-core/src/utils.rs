@@ -1,27 +1,138 @@
 use crate::error::DownloadError;
+use crate::minecraft::download_options::DownloadOptions;
+use crate::minecraft::installation_update::Progress;
+use crate::store;
+use crossbeam_channel::RecvTimeoutError;
+use reqwest::header::{CONTENT_RANGE, RANGE};
+use reqwest::StatusCode;
 use sha1::{Digest, Sha1};
-use std::fs::File;
-use std::path::Path;
+use sha2::Sha512;
+use std::collections::{HashSet, VecDeque};
+use std::fs;
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
 
-pub fn download_file(url: &str, dest: impl AsRef<Path>) -> Result<(), DownloadError> {
+#[derive(Clone, Debug)]
+pub struct DownloadTask {
+    pub url: String,
+    pub dest: PathBuf,
+    pub sha1: Option<Vec<u8>>,
+    pub size: Option<usize>,
+}
+
+// Resumes the download if `dest` already exists. `on_progress` receives the number of bytes
+// written to `dest` so far.
+pub fn download_file(
+    url: &str,
+    dest: impl AsRef<Path>,
+    on_progress: &mut dyn FnMut(u64),
+) -> Result<(), DownloadError> {
     trace!("Downloading file: {}", url);
 
-    let mut response = reqwest::blocking::get(url)
-        .map_err(DownloadError::Reqwest)?
+    let existing_size = fs::metadata(&dest)
+        .map(|metadata| metadata.len())
+        .unwrap_or(0);
+
+    let mut request = reqwest::blocking::Client::new().get(url);
+    if existing_size > 0 {
+        trace!("Resuming download at byte {}", existing_size);
+        request = request.header(RANGE, format!("bytes={}-", existing_size));
+    }
+
+    let response = request
+        .send()
+        .map_err(|err| DownloadError::from_reqwest(url, err))?;
+
+    // Nothing left to download if the server agrees on the size
+    if existing_size > 0 && response.status() == StatusCode::RANGE_NOT_SATISFIABLE {
+        if content_range(&response).1 == Some(existing_size) {
+            trace!("File is already complete");
+            return Ok(());
+        }
+        return restart_download(url, dest, on_progress);
+    }
+
+    let mut response = response
         .error_for_status()
-        .map_err(DownloadError::Reqwest)?;
+        .map_err(|err| DownloadError::from_reqwest(url, err))?;
+
+    // The server has to continue exactly where the partial file ends
+    let resumed = response.status() == StatusCode::PARTIAL_CONTENT;
+    if resumed && content_range(&response).0 != Some(existing_size) {
+        return restart_download(url, dest, on_progress);
+    }
 
-    let mut file = File::create(&dest).map_err(DownloadError::IO)?;
+    let (mut file, mut written) = match resumed {
+        true => (
+            OpenOptions::new()
+                .append(true)
+                .open(&dest)
+                .map_err(DownloadError::IO)?,
+            existing_size,
+        ),
+        false => (File::create(&dest).map_err(DownloadError::IO)?, 0),
+    };
+    on_progress(written);
 
-    let _total_size = std::io::copy(&mut response, &mut file).map_err(DownloadError::IO)?;
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let read = response
+            .read(&mut buffer)
+            .map_err(|err| DownloadError::from_io(url, err))?;
+        if read == 0 {
+            break;
+        }
+
+        file.write_all(&buffer[..read]).map_err(DownloadError::IO)?;
+        written += read as u64;
+        on_progress(written);
+    }
 
     Ok(())
 }
 
+fn restart_download(
+    url: &str,
+    dest: impl AsRef<Path>,
+    on_progress: &mut dyn FnMut(u64),
+) -> Result<(), DownloadError> {
+    trace!("Partial file does not match the server, restarting download");
+    fs::remove_file(&dest).map_err(DownloadError::IO)?;
+
+    download_file(url, dest, on_progress)
+}
+
+// Start and total size of `Content-Range`, e.g. `bytes 100-199/200` or `bytes */200`
+fn content_range(response: &reqwest::blocking::Response) -> (Option<u64>, Option<u64>) {
+    let value = match response
+        .headers()
+        .get(CONTENT_RANGE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("bytes "))
+    {
+        Some(value) => value,
+        None => return (None, None),
+    };
+
+    let (range, total) = value.split_once('/').unwrap_or((value, "*"));
+    let start = range
+        .split_once('-')
+        .and_then(|(start, _)| start.trim().parse().ok());
+
+    (start, total.trim().parse().ok())
+}
+
+// Consults the shared store first if `store_path` is set and the checksum is known
 pub fn download_file_check(
     url: &str,
     dest: impl AsRef<Path>,
     remote_sha: Option<Vec<u8>>,
+    store_path: Option<&Path>,
+    on_progress: &mut dyn FnMut(u64),
 ) -> Result<(), DownloadError> {
     trace!("Checked download of file: {}", url);
 
@@ -38,29 +149,168 @@ pub fn download_file_check(
 
                 if remote_sha == &local_sha {
                     trace!("Existing file is correct");
+                    if let Some(store_path) = store_path {
+                        store::insert(store_path, remote_sha, &dest)?;
+                    }
                     return Ok(());
-                } else {
-                    trace!("Existing file does not match checksum");
-                    download_file(url, &dest)?;
                 }
+
+                trace!("Existing file does not match checksum");
             }
         }
     } else {
         trace!("File does not exist yet");
+    }
 
-        download_file(url, &dest)?;
+    if let (Some(store_path), Some(remote_sha)) = (store_path, &remote_sha) {
+        if store::fetch(store_path, remote_sha, &dest)? {
+            return Ok(());
+        }
     }
 
+    // Without a checksum a resumed file could never be verified
+    let part_path = part_path(&dest);
+    if remote_sha.is_none() && part_path.exists() {
+        fs::remove_file(&part_path).map_err(DownloadError::IO)?;
+    }
+    download_file(url, &part_path, on_progress)?;
+
     if let Some(remote_sha) = &remote_sha {
-        let local_sha = get_sha1(&dest)?;
+        let local_sha = get_sha1(&part_path)?;
         if remote_sha != &local_sha {
+            // Start from scratch next time
+            let _ = fs::remove_file(&part_path);
             return Err(DownloadError::Sha1Mismatch(url.to_string()));
         }
     }
 
+    fs::rename(&part_path, &dest).map_err(DownloadError::IO)?;
+
+    if let (Some(store_path), Some(remote_sha)) = (store_path, &remote_sha) {
+        store::insert(store_path, remote_sha, &dest)?;
+    }
+
     Ok(())
 }
 
+// Retries transient failures according to the retry policy in `download_options`
+pub fn download_file_retry(
+    url: &str,
+    dest: impl AsRef<Path>,
+    remote_sha: Option<Vec<u8>>,
+    download_options: &DownloadOptions,
+    on_progress: &mut dyn FnMut(u64),
+) -> Result<(), DownloadError> {
+    retry(url, download_options, || {
+        download_file_check(
+            url,
+            &dest,
+            remote_sha.clone(),
+            download_options.store_path.as_deref(),
+            on_progress,
+        )
+    })
+}
+
+// Runs `request` again after transient failures, waiting longer after every attempt
+pub(crate) fn retry<T>(
+    url: &str,
+    download_options: &DownloadOptions,
+    mut request: impl FnMut() -> Result<T, DownloadError>,
+) -> Result<T, DownloadError> {
+    let mut attempt = 1;
+
+    loop {
+        match request() {
+            Ok(value) => return Ok(value),
+            Err(err) if err.is_transient() => {
+                if attempt >= download_options.max_attempts {
+                    return Err(DownloadError::RetriesExhausted(
+                        url.to_string(),
+                        attempt,
+                        Box::new(err),
+                    ));
+                }
+
+                let delay = download_options.backoff(attempt);
+                debug!(
+                    "Attempt {} for '{}' failed: {} - Retrying in {:?}",
+                    attempt, url, err, delay
+                );
+                thread::sleep(delay);
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+// Hardlinks only work on the same filesystem. Copying uses reflinks where the filesystem
+// supports them. The link is created under a temporary name and renamed into place, so an
+// existing `dest` is replaced instead of written through.
+pub(crate) fn link_or_copy(
+    src: impl AsRef<Path>,
+    dest: impl AsRef<Path>,
+) -> Result<(), DownloadError> {
+    let mut file_name = dest
+        .as_ref()
+        .file_name()
+        .map(|name| name.to_os_string())
+        .unwrap_or_default();
+    file_name.push(format!(".{}.tmp", uuid::Uuid::new_v4().to_simple()));
+    let temp_path = dest.as_ref().with_file_name(file_name);
+
+    match fs::hard_link(&src, &temp_path) {
+        Ok(_) => {}
+        Err(err) if is_link_unsupported(&err) => {
+            fs::copy(&src, &temp_path).map_err(DownloadError::IO)?;
+        }
+        Err(err) => return Err(DownloadError::IO(err)),
+    }
+
+    let result = fs::rename(&temp_path, &dest).map_err(DownloadError::IO);
+
+    // Renaming onto another link of the same file leaves both names in place
+    if temp_path.exists() {
+        let _ = fs::remove_file(&temp_path);
+    }
+
+    result
+}
+
+fn is_link_unsupported(err: &std::io::Error) -> bool {
+    #[cfg(unix)]
+    const CROSS_DEVICE: i32 = 18; // EXDEV
+    #[cfg(windows)]
+    const CROSS_DEVICE: i32 = 17; // ERROR_NOT_SAME_DEVICE
+    #[cfg(not(any(unix, windows)))]
+    const CROSS_DEVICE: i32 = -1;
+
+    err.kind() == std::io::ErrorKind::Unsupported || err.raw_os_error() == Some(CROSS_DEVICE)
+}
+
+// Joins `relative` onto `base`, unless it is absolute or has `..` or `.` components
+pub(crate) fn enclosed_path(base: impl AsRef<Path>, relative: &str) -> Option<PathBuf> {
+    let path = Path::new(relative);
+    let is_enclosed = path.components().next().is_some()
+        && path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+
+    is_enclosed.then(|| base.as_ref().join(path))
+}
+
+pub(crate) fn part_path(dest: impl AsRef<Path>) -> PathBuf {
+    let mut file_name = dest
+        .as_ref()
+        .file_name()
+        .map(|name| name.to_os_string())
+        .unwrap_or_default();
+    file_name.push(".part");
+
+    dest.as_ref().with_file_name(file_name)
+}
+
 pub fn get_sha1(file: impl AsRef<Path>) -> Result<Vec<u8>, DownloadError> {
     trace!(
         "Generathing SHA1 for file {}",
@@ -74,3 +324,355 @@ pub fn get_sha1(file: impl AsRef<Path>) -> Result<Vec<u8>, DownloadError> {
 
     Ok(hash.to_vec())
 }
+
+pub fn get_sha512(file: impl AsRef<Path>) -> Result<Vec<u8>, DownloadError> {
+    trace!(
+        "Generathing SHA512 for file {}",
+        file.as_ref().to_string_lossy()
+    );
+
+    let mut file = File::open(&file).map_err(DownloadError::IO)?;
+    let mut hasher: Sha512 = Sha512::new();
+    std::io::copy(&mut file, &mut hasher).map_err(DownloadError::IO)?;
+    let hash = hasher.finalize();
+
+    Ok(hash.to_vec())
+}
+
+// Downloads all tasks using up to `download_options.workers` threads. `on_update` is called on
+// the calling thread whenever a file finishes and periodically while bytes are transferred.
+pub fn download_files<F>(
+    tasks: &[DownloadTask],
+    download_options: &DownloadOptions,
+    cancel: &AtomicBool,
+    mut on_update: F,
+) -> Result<(), DownloadError>
+where
+    F: FnMut(Progress),
+{
+    // Two workers must never write the same file at once
+    let tasks = &unique_tasks(tasks);
+    let workers = download_options.workers;
+    trace!("Downloading {} files with {} workers", tasks.len(), workers);
+
+    let (task_sender, task_receiver) = crossbeam_channel::unbounded();
+    for index in 0..tasks.len() {
+        let _ = task_sender.send(index);
+    }
+    drop(task_sender);
+
+    let abort = AtomicBool::new(false);
+    let (event_sender, event_receiver) = crossbeam_channel::unbounded();
+
+    thread::scope(|scope| {
+        for _ in 0..workers.clamp(1, tasks.len().max(1)) {
+            let task_receiver = task_receiver.clone();
+            let event_sender = event_sender.clone();
+            let abort = &abort;
+
+            scope.spawn(move || {
+                while let Ok(index) = task_receiver.recv() {
+                    if cancel.load(Ordering::Relaxed) || abort.load(Ordering::Relaxed) {
+                        break;
+                    }
+
+                    let task: &DownloadTask = &tasks[index];
+                    let result = download_task(task, download_options, &mut |bytes| {
+                        let _ = event_sender.send(TaskEvent::Progress(index, bytes));
+                    });
+
+                    let event = match result {
+                        Ok(_) => TaskEvent::Finished(index),
+                        Err(err) => TaskEvent::Failed(err),
+                    };
+                    if event_sender.send(event).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(event_sender);
+
+        let mut tracker = ProgressTracker::new(tasks);
+        let mut error = None;
+
+        loop {
+            match event_receiver.recv_timeout(PROGRESS_INTERVAL) {
+                Ok(TaskEvent::Progress(index, bytes)) => tracker.set_bytes(index, bytes),
+                Ok(TaskEvent::Finished(index)) => {
+                    tracker.finish(index);
+                    on_update(tracker.progress());
+                }
+                Ok(TaskEvent::Failed(err)) => {
+                    abort.store(true, Ordering::Relaxed);
+                    if error.is_none() {
+                        error = Some(err);
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+
+            if tracker.last_update.elapsed() >= PROGRESS_INTERVAL {
+                on_update(tracker.progress());
+            }
+        }
+
+        match error {
+            None => Ok(()),
+            Some(err) => Err(err),
+        }
+    })
+}
+
+// Keeps the first task for every destination
+fn unique_tasks(tasks: &[DownloadTask]) -> Vec<DownloadTask> {
+    let mut destinations = HashSet::new();
+
+    tasks
+        .iter()
+        .filter(|task| destinations.insert(task.dest.as_path()))
+        .cloned()
+        .collect()
+}
+
+fn download_task(
+    task: &DownloadTask,
+    download_options: &DownloadOptions,
+    on_progress: &mut dyn FnMut(u64),
+) -> Result<(), DownloadError> {
+    if let Some(parent) = task.dest.parent() {
+        fs::create_dir_all(parent).map_err(DownloadError::IO)?;
+    }
+
+    download_file_retry(
+        &task.url,
+        &task.dest,
+        task.sha1.clone(),
+        download_options,
+        on_progress,
+    )
+}
+
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+const SPEED_WINDOW: Duration = Duration::from_secs(5);
+
+enum TaskEvent {
+    Progress(usize, u64),
+    Finished(usize),
+    Failed(DownloadError),
+}
+
+struct ProgressTracker<'a> {
+    tasks: &'a [DownloadTask],
+    task_bytes: Vec<u64>,
+    downloaded_bytes: u64,
+    // Only bytes which went over the network count towards the speed
+    transferred_bytes: u64,
+    finished: usize,
+    current: Option<usize>,
+    samples: VecDeque<(Instant, u64)>,
+    last_update: Instant,
+}
+
+impl<'a> ProgressTracker<'a> {
+    fn new(tasks: &'a [DownloadTask]) -> Self {
+        Self {
+            tasks,
+            task_bytes: vec![0; tasks.len()],
+            downloaded_bytes: 0,
+            transferred_bytes: 0,
+            finished: 0,
+            current: None,
+            samples: VecDeque::new(),
+            last_update: Instant::now(),
+        }
+    }
+
+    fn set_bytes(&mut self, index: usize, bytes: u64) {
+        // Bytes can go down again if a download is restarted
+        let previous = self.task_bytes[index];
+        self.downloaded_bytes = self.downloaded_bytes - previous + bytes;
+        self.transferred_bytes += bytes.saturating_sub(previous);
+        self.task_bytes[index] = bytes;
+        self.current = Some(index);
+    }
+
+    fn finish(&mut self, index: usize) {
+        // Files which were already present or linked from the store never report any bytes
+        if let Some(size) = self.tasks[index].size {
+            let size = size as u64;
+            self.downloaded_bytes = self.downloaded_bytes - self.task_bytes[index] + size;
+            self.task_bytes[index] = size;
+        }
+
+        self.finished += 1;
+        self.current = Some(index);
+    }
+
+    fn total_bytes(&self) -> u64 {
+        self.tasks
+            .iter()
+            .zip(&self.task_bytes)
+            .map(|(task, bytes)| task.size.map(|size| size as u64).unwrap_or(*bytes))
+            .sum()
+    }
+
+    fn progress(&mut self) -> Progress {
+        let now = Instant::now();
+        self.last_update = now;
+
+        // Rolling download speed
+        self.samples.push_back((now, self.transferred_bytes));
+        while let Some((time, _)) = self.samples.front() {
+            if now.duration_since(*time) <= SPEED_WINDOW {
+                break;
+            }
+            self.samples.pop_front();
+        }
+
+        let speed = match (self.samples.front(), self.samples.back()) {
+            (Some((first_time, first_bytes)), Some((last_time, last_bytes)))
+                if last_time > first_time =>
+            {
+                last_bytes.saturating_sub(*first_bytes) as f64
+                    / last_time.duration_since(*first_time).as_secs_f64()
+            }
+            _ => 0.0,
+        };
+
+        let total_bytes = self.total_bytes();
+        let eta = match speed > 0.0 {
+            true => Some(Duration::from_secs_f64(
+                total_bytes.saturating_sub(self.downloaded_bytes) as f64 / speed,
+            )),
+            false => None,
+        };
+
+        let mut progress = Progress {
+            total_files: self.tasks.len(),
+            current_file: self.finished,
+            downloaded_bytes: self.downloaded_bytes,
+            total_bytes,
+            speed,
+            eta,
+            ..Default::default()
+        };
+
+        if let Some(index) = self.current {
+            let task = &self.tasks[index];
+            progress.current_file_url = task.url.clone();
+            progress.current_file_size = task.size;
+            progress.current_file_bytes = self.task_bytes[index];
+        }
+
+        progress
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server;
+    use crate::test_server::Response;
+    use std::sync::{Arc, Mutex};
+
+    const DATA: &[u8] = b"0123456789";
+
+    // Partial file with the first `existing` bytes, removed once the test ends
+    struct PartialFile(PathBuf);
+
+    impl PartialFile {
+        fn new(existing: usize) -> Self {
+            let path =
+                std::env::temp_dir().join(format!("blocky-download-{}", uuid::Uuid::new_v4()));
+            fs::write(&path, &DATA[..existing]).unwrap();
+            Self(path)
+        }
+    }
+
+    impl Drop for PartialFile {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    // Serves `DATA`, ranged requests are answered by `ranged`. Records the requested ranges.
+    fn serve(
+        ranged: impl Fn(&str) -> Response + Send + 'static,
+    ) -> (String, Arc<Mutex<Vec<Option<String>>>>) {
+        let ranges = Arc::new(Mutex::new(vec![]));
+
+        let requested = ranges.clone();
+        let base_url = test_server::serve(move |request| {
+            let range = request.header("range").map(String::from);
+            requested.lock().unwrap().push(range.clone());
+
+            match range {
+                Some(range) => ranged(&range),
+                None => Response::ok(DATA),
+            }
+        });
+
+        (format!("{}/file", base_url), ranges)
+    }
+
+    #[test]
+    fn resume_matching_range() {
+        let file = PartialFile::new(4);
+        let (url, ranges) = serve(|range| {
+            assert_eq!(range, "bytes=4-");
+            Response::new("206 Partial Content", &DATA[4..]).header("Content-Range", "bytes 4-9/10")
+        });
+
+        let mut progress = vec![];
+        download_file(&url, &file.0, &mut |written| progress.push(written)).unwrap();
+
+        assert_eq!(fs::read(&file.0).unwrap(), DATA);
+        assert_eq!(
+            *ranges.lock().unwrap(),
+            vec![Some(String::from("bytes=4-"))]
+        );
+        assert_eq!(progress.first(), Some(&4));
+        assert_eq!(progress.last(), Some(&10));
+    }
+
+    #[test]
+    fn restart_on_wrong_range() {
+        let file = PartialFile::new(4);
+        let (url, ranges) = serve(|_| {
+            Response::new("206 Partial Content", &DATA[2..]).header("Content-Range", "bytes 2-9/10")
+        });
+
+        download_file(&url, &file.0, &mut |_| {}).unwrap();
+
+        assert_eq!(fs::read(&file.0).unwrap(), DATA);
+        assert_eq!(
+            *ranges.lock().unwrap(),
+            vec![Some(String::from("bytes=4-")), None]
+        );
+    }
+
+    #[test]
+    fn full_response_to_range_request() {
+        let file = PartialFile::new(4);
+        let (url, ranges) = serve(|_| Response::ok(DATA));
+
+        download_file(&url, &file.0, &mut |_| {}).unwrap();
+
+        assert_eq!(fs::read(&file.0).unwrap(), DATA);
+        assert_eq!(ranges.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn complete_file_is_kept() {
+        let file = PartialFile::new(DATA.len());
+        let (url, _) = serve(|_| {
+            Response::new("416 Range Not Satisfiable", vec![]).header("Content-Range", "bytes */10")
+        });
+
+        download_file(&url, &file.0, &mut |_| {}).unwrap();
+
+        assert_eq!(fs::read(&file.0).unwrap(), DATA);
+    }
+}
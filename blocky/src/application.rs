@@ -158,6 +158,14 @@ impl BlockyApplication {
         });
         self.add_action(&action_add_instance);
 
+        // app.import-modpack
+        let action_import_modpack = gio::SimpleAction::new("import-modpack", None);
+        action_import_modpack.connect_activate(move |_, _| {
+            debug!("Show import-modpack dialog");
+            crate::ui::modpack_import::show_import_dialog();
+        });
+        self.add_action(&action_import_modpack);
+
         // app.add-profile
         let action_add_profile = gio::SimpleAction::new("add-profile", None);
         action_add_profile.connect_activate(move |_, _| {
@@ -0,0 +1,30 @@
+use crate::paths;
+use crate::settings;
+use crate::settings::SettingKey;
+use blocky_core::minecraft::download_options::{DownloadOptions, DownloadOptionsBuilder};
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub fn download_options() -> DownloadOptions {
+    let workers = settings::get_integer(SettingKey::DownloadWorkers).max(1) as usize;
+    let metadata_max_age = settings::get_integer(SettingKey::MetadataMaxAge).max(0) as u64;
+
+    let mut builder = DownloadOptionsBuilder::default();
+    builder
+        .workers(workers)
+        .cache_path(paths::CACHE.join("metadata"))
+        .cache_max_age(Duration::from_secs(metadata_max_age * 60));
+
+    // Same Java as the game, see `build_launch_options`
+    if settings::get_bool(SettingKey::UseManagedJava) {
+        builder.java_runtimes_path(paths::JAVA_RUNTIMES.clone());
+    } else {
+        builder.java_exec(settings::get_string(SettingKey::JavaExec));
+    }
+
+    if settings::get_bool(SettingKey::EnableStore) {
+        builder.store_path(PathBuf::from(settings::get_string(SettingKey::StoreDir)));
+    }
+
+    builder.build().unwrap()
+}
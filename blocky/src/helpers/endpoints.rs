@@ -0,0 +1,52 @@
+use crate::settings;
+use crate::settings::SettingKey;
+use blocky_core::endpoints::{Endpoints, EndpointsBuilder};
+
+pub fn endpoints() -> Endpoints {
+    let mut builder = EndpointsBuilder::default();
+
+    let version_manifest_url = settings::get_string(SettingKey::VersionManifestUrl);
+    if !version_manifest_url.trim().is_empty() {
+        builder.version_manifest_url(version_manifest_url.trim().to_string());
+    }
+
+    let libraries_url = settings::get_string(SettingKey::LibrariesUrl);
+    if !libraries_url.trim().is_empty() {
+        builder.libraries_base_url(libraries_url.trim().to_string());
+    }
+
+    let assets_url = settings::get_string(SettingKey::AssetsUrl);
+    if !assets_url.trim().is_empty() {
+        builder.assets_base_url(assets_url.trim().to_string());
+    }
+
+    let java_runtimes_url = settings::get_string(SettingKey::JavaRuntimesUrl);
+    if !java_runtimes_url.trim().is_empty() {
+        builder.java_runtimes_url(java_runtimes_url.trim().to_string());
+    }
+
+    // Entries look like `<prefix> <replacement>`, e.g. to redirect version data and client jars
+    let url_rewrites = settings::get_strings(SettingKey::UrlRewrites)
+        .iter()
+        .filter_map(|rewrite| rewrite.trim().split_once(char::is_whitespace))
+        .map(|(prefix, replacement)| (prefix.to_string(), replacement.trim().to_string()))
+        .collect::<Vec<_>>();
+    builder.url_rewrites(url_rewrites);
+
+    let modrinth_api_url = settings::get_string(SettingKey::ModrinthApiUrl);
+    if !modrinth_api_url.trim().is_empty() {
+        builder.modrinth_api_url(modrinth_api_url.trim().to_string());
+    }
+
+    let curseforge_api_url = settings::get_string(SettingKey::CurseForgeApiUrl);
+    if !curseforge_api_url.trim().is_empty() {
+        builder.curseforge_api_url(curseforge_api_url.trim().to_string());
+    }
+
+    let curseforge_api_key = settings::get_string(SettingKey::CurseForgeApiKey);
+    if !curseforge_api_key.trim().is_empty() {
+        builder.curseforge_api_key(curseforge_api_key.trim().to_string());
+    }
+
+    builder.build().unwrap()
+}
@@ -1,6 +1,8 @@
-use crate::helpers::find_instance;
+use crate::helpers::{download_options, endpoints, find_instance};
 use anyhow::anyhow;
+use blocky_core::minecraft::install_state::InstallState;
 use blocky_core::minecraft::installation_update::InstallationUpdate;
+use blocky_core::minecraft::verify_report::VerifyReport;
 use crossbeam_channel::{Receiver, Sender};
 use std::path::Path;
 use std::sync::atomic::AtomicBool;
@@ -14,7 +16,12 @@ pub fn install_threaded(
     cancel: Arc<AtomicBool>,
 ) -> Receiver<InstallationUpdate> {
     let (tx, rx) = crossbeam_channel::unbounded();
-    thread::spawn(move || install(instance_uuid, instances_path, tx, cancel));
+    thread::spawn(move || {
+        if let Err(err) = install(instance_uuid, instances_path, tx.clone(), cancel) {
+            error!("Error while installing instance - {}", err);
+            let _ = tx.send(InstallationUpdate::Failed(err.to_string()));
+        }
+    });
     rx
 }
 
@@ -27,15 +34,72 @@ fn install(
     let instance = find_instance(instance_uuid, instances_path)?
         .ok_or_else(|| anyhow!("Instance not found: {}", instance_uuid))?;
 
-    instance.full_install(sender, cancel)?;
+    instance.full_install(&endpoints(), &download_options(), sender, cancel)?;
 
     Ok(())
 }
 
-pub fn check_install_state(instance_uuid: Uuid, instances_path: String) -> anyhow::Result<bool> {
+pub fn repair_threaded(
+    instance_uuid: Uuid,
+    instances_path: String,
+    cancel: Arc<AtomicBool>,
+) -> Receiver<InstallationUpdate> {
+    let (tx, rx) = crossbeam_channel::unbounded();
+    thread::spawn(move || {
+        if let Err(err) = repair(instance_uuid, instances_path, tx.clone(), cancel) {
+            error!("Error while repairing instance - {}", err);
+            let _ = tx.send(InstallationUpdate::Failed(err.to_string()));
+        }
+    });
+    rx
+}
+
+fn repair(
+    instance_uuid: Uuid,
+    instances_path: impl AsRef<Path>,
+    sender: Sender<InstallationUpdate>,
+    cancel: Arc<AtomicBool>,
+) -> anyhow::Result<()> {
+    let instance = find_instance(instance_uuid, instances_path)?
+        .ok_or_else(|| anyhow!("Instance not found: {}", instance_uuid))?;
+
+    let report = instance.repair(&endpoints(), &download_options(), sender, cancel)?;
+    info!(
+        "Verified {} files, repaired {} and removed {}",
+        report.checked_files,
+        report.broken_files(),
+        report.extraneous.len()
+    );
+
+    Ok(())
+}
+
+pub fn verify(
+    instance_uuid: Uuid,
+    instances_path: impl AsRef<Path>,
+) -> anyhow::Result<VerifyReport> {
     let instance = find_instance(instance_uuid, instances_path)?
         .ok_or_else(|| anyhow!("Instance not found: {}", instance_uuid))?;
-    let installed = instance.check_installed()?;
 
-    Ok(installed)
+    Ok(instance.verify()?)
+}
+
+pub fn check_install_state(
+    instance_uuid: Uuid,
+    instances_path: String,
+) -> anyhow::Result<InstallState> {
+    let instance = find_instance(instance_uuid, instances_path)?
+        .ok_or_else(|| anyhow!("Instance not found: {}", instance_uuid))?;
+    let mut install_state = instance.check_installed()?;
+
+    // Mojang occasionally republishes versions
+    if install_state == InstallState::Installed {
+        match instance.has_updated_metadata(&endpoints(), &download_options()) {
+            Ok(true) => install_state = InstallState::Outdated,
+            Ok(false) => {}
+            Err(err) => warn!("Could not check version data for updates - {}", err),
+        }
+    }
+
+    Ok(install_state)
 }
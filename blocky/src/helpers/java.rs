@@ -0,0 +1,24 @@
+use crate::paths;
+use blocky_core::instance::Instance;
+use blocky_core::java::discovery::{discover_java, rank_java};
+use blocky_core::java::models::java_installation::{JavaCompatibility, JavaInstallation};
+
+// Compatibility is only known once the instance is installed
+pub fn find_java(instance: &Instance) -> Vec<(JavaInstallation, Option<JavaCompatibility>)> {
+    debug!(
+        "Discovering Java installations for instance '{}'",
+        &instance.uuid
+    );
+    let installations = discover_java(&[paths::JAVA_RUNTIMES.clone()]);
+
+    match instance.read_version_data() {
+        Ok(version_data) => rank_java(installations, version_data.java_major_version())
+            .into_iter()
+            .map(|(installation, compatibility)| (installation, Some(compatibility)))
+            .collect(),
+        Err(_) => installations
+            .into_iter()
+            .map(|installation| (installation, None))
+            .collect(),
+    }
+}
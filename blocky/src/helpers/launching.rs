@@ -1,4 +1,5 @@
 use crate::helpers::{find_instance, find_profile, find_refresh_save};
+use crate::paths;
 use crate::settings;
 use crate::settings::SettingKey;
 use anyhow::anyhow;
@@ -72,8 +73,10 @@ pub fn build_launch_options(
             .max_memory(settings::get_integer(SettingKey::MaxMemory) as u32);
     }
 
-    if !instance.java_exec.trim().is_empty() {
+    if instance.enable_java_exec && !instance.java_exec.trim().is_empty() {
         builder.java_exec(instance.java_exec);
+    } else if settings::get_bool(SettingKey::UseManagedJava) {
+        builder.java_runtimes_path(paths::JAVA_RUNTIMES.clone());
     } else {
         builder.java_exec(settings::get_string(SettingKey::JavaExec));
     }
@@ -0,0 +1,31 @@
+use crate::helpers::{download_options, endpoints};
+use blocky_core::loader::models::loader::LoaderKind;
+use blocky_core::loader::{fabric, forge, neoforge, quilt};
+
+// Loader versions supporting `game_version`, newest first
+pub fn get_loader_versions(kind: LoaderKind, game_version: &str) -> anyhow::Result<Vec<String>> {
+    debug!("Getting {} loader versions for '{}'", kind, game_version);
+
+    let versions = match kind {
+        LoaderKind::Fabric => {
+            fabric::loader_versions(game_version, &endpoints(), &download_options())?
+                .into_iter()
+                .map(|version| version.loader.version)
+                .collect()
+        }
+        LoaderKind::Forge => {
+            forge::loader_versions(game_version, &endpoints(), &download_options())?
+        }
+        LoaderKind::Quilt => {
+            quilt::loader_versions(game_version, &endpoints(), &download_options())?
+                .into_iter()
+                .map(|version| version.loader.version)
+                .collect()
+        }
+        LoaderKind::NeoForge => {
+            neoforge::loader_versions(game_version, &endpoints(), &download_options())?
+        }
+    };
+
+    Ok(versions)
+}
@@ -1,11 +1,23 @@
+mod download_options;
+mod endpoints;
 mod installation;
 mod instances;
+mod java;
 mod launching;
+mod loaders;
+mod modpacks;
+mod mods;
 mod profiles;
 mod version_manifest;
 
+pub use download_options::*;
+pub use endpoints::*;
 pub use installation::*;
 pub use instances::*;
+pub use java::*;
 pub use launching::*;
+pub use loaders::*;
+pub use modpacks::*;
+pub use mods::*;
 pub use profiles::*;
 pub use version_manifest::*;
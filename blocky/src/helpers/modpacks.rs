@@ -0,0 +1,106 @@
+use crate::helpers::{download_options, endpoints};
+use crate::settings;
+use crate::settings::SettingKey;
+use blocky_core::instance::Instance;
+use blocky_core::minecraft::installation_update::InstallationUpdate;
+use blocky_core::modpack::curseforge::CurseForgeReport;
+use blocky_core::modpack::{curseforge, mrpack};
+use crossbeam_channel::{Receiver, Sender};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use uuid::Uuid;
+
+// Instance for the game version and loader declared by the modpack
+pub fn create_modpack_instance(pack_path: impl AsRef<Path>) -> anyhow::Result<Instance> {
+    debug!(
+        "Creating instance for modpack '{}'",
+        pack_path.as_ref().to_string_lossy()
+    );
+    let mut builder = match is_curseforge_pack(&pack_path) {
+        true => curseforge::instance_builder(&curseforge::read_manifest(&pack_path)?)?,
+        false => mrpack::instance_builder(&mrpack::read_index(&pack_path)?)?,
+    };
+
+    let uuid = Uuid::new_v4();
+    let mut instance_path = PathBuf::from(settings::get_string(SettingKey::InstancesDir));
+    instance_path.push(uuid.to_string());
+
+    let instance = builder
+        .uuid(uuid)
+        .instance_path(instance_path.to_string_lossy().to_string())
+        .libraries_path(settings::get_string(SettingKey::LibrariesDir))
+        .assets_path(settings::get_string(SettingKey::AssetsDir))
+        .java_exec(settings::get_string(SettingKey::JavaExec))
+        .build()?;
+
+    Ok(instance)
+}
+
+// The report follows the updates once the import finished, it is not sent if the import
+// failed or got canceled
+pub fn install_modpack_threaded(
+    instance: Instance,
+    pack_path: PathBuf,
+    cancel: Arc<AtomicBool>,
+) -> (Receiver<InstallationUpdate>, Receiver<CurseForgeReport>) {
+    let (tx, rx) = crossbeam_channel::unbounded();
+    let (report_tx, report_rx) = crossbeam_channel::bounded(1);
+    thread::spawn(
+        move || match install_modpack(&instance, &pack_path, tx.clone(), cancel) {
+            Ok(Some(report)) => {
+                let _ = report_tx.send(report);
+            }
+            Ok(None) => {}
+            Err(err) => {
+                error!("Error while installing modpack - {}", err);
+                let _ = tx.send(InstallationUpdate::Failed(err.to_string()));
+            }
+        },
+    );
+    (rx, report_rx)
+}
+
+fn install_modpack(
+    instance: &Instance,
+    pack_path: &Path,
+    sender: Sender<InstallationUpdate>,
+    cancel: Arc<AtomicBool>,
+) -> anyhow::Result<Option<CurseForgeReport>> {
+    if !is_curseforge_pack(pack_path) {
+        instance.install_mrpack(
+            pack_path,
+            &endpoints(),
+            &download_options(),
+            sender,
+            cancel.clone(),
+        )?;
+        return Ok((!cancel.load(Ordering::Relaxed)).then(CurseForgeReport::default));
+    }
+
+    let report = instance.install_curseforge_pack(
+        pack_path,
+        &endpoints(),
+        &download_options(),
+        sender,
+        cancel,
+    )?;
+    for file in report.iter().flat_map(|report| &report.unresolved) {
+        warn!(
+            "Skipped file {} of project {}: {}",
+            file.file_id, file.project_id, &file.reason
+        );
+    }
+
+    Ok(report)
+}
+
+// CurseForge modpacks are plain zip archives
+fn is_curseforge_pack(pack_path: impl AsRef<Path>) -> bool {
+    pack_path
+        .as_ref()
+        .extension()
+        .map(|extension| extension.eq_ignore_ascii_case("zip"))
+        .unwrap_or(false)
+}
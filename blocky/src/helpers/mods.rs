@@ -0,0 +1,49 @@
+use crate::helpers::{download_options, endpoints};
+use blocky_core::instance::Instance;
+use blocky_core::modrinth::api;
+use blocky_core::modrinth::error::ModrinthError;
+use blocky_core::modrinth::models::installed_mod::InstalledMod;
+use blocky_core::modrinth::models::project::SearchHit;
+use blocky_core::modrinth::resolve::Resolution;
+
+const SEARCH_LIMIT: usize = 20;
+
+pub fn get_installed_mods(instance: &Instance) -> anyhow::Result<Vec<InstalledMod>> {
+    debug!("Getting installed mods of instance '{}'", &instance.name);
+
+    Ok(instance.installed_mods(&endpoints())?)
+}
+
+// Projects on Modrinth matching `query` which have a version for the instance
+pub fn search_mods(instance: &Instance, query: &str) -> anyhow::Result<Vec<SearchHit>> {
+    debug!("Searching mods for instance '{}'", &instance.name);
+
+    let response = api::search(query, &instance.mod_filter(), 0, SEARCH_LIMIT, &endpoints())?;
+
+    Ok(response.hits)
+}
+
+// The newest compatible version of the project with its dependencies
+pub fn resolve_mod(instance: &Instance, project_id: &str) -> anyhow::Result<Resolution> {
+    info!("Resolving mod '{}'", project_id);
+
+    let endpoints = endpoints();
+    let version = api::project_versions(project_id, &instance.mod_filter(), &endpoints)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| ModrinthError::NoCompatibleVersion(project_id.to_string()))?;
+
+    Ok(instance.resolve_mod(version, &endpoints)?)
+}
+
+pub fn install_mod(instance: &Instance, resolution: &Resolution) -> anyhow::Result<()> {
+    info!("Installing {} mods", resolution.versions.len());
+
+    Ok(instance.install_resolution(resolution, &endpoints(), &download_options())?)
+}
+
+pub fn remove_mod(instance: &Instance, file_name: &str) -> anyhow::Result<()> {
+    info!("Removing mod '{}'", file_name);
+
+    Ok(instance.remove_mod(file_name)?)
+}
@@ -1,3 +1,4 @@
+use crate::helpers::endpoints;
 use anyhow::anyhow;
 use blocky_core::profile::Profile;
 use std::collections::HashMap;
@@ -15,7 +16,11 @@ pub fn find_refresh_save(uuid: Uuid, path: impl AsRef<Path>) -> anyhow::Result<P
     debug!("Checking if token expired");
     if let Some(minecraft_token) = &profile.minecraft {
         if minecraft_token.check_expired().is_err() {
-            profile.refresh(crate::config::MS_GRAPH_ID, crate::config::MS_GRAPH_SECRET)?;
+            profile.refresh(
+                crate::config::MS_GRAPH_ID,
+                crate::config::MS_GRAPH_SECRET,
+                &endpoints(),
+            )?;
             save_profile(profile.clone(), &path)?;
         }
     }
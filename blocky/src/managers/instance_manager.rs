@@ -4,7 +4,11 @@ use crate::settings::SettingKey;
 use crate::{helpers, settings, BlockyApplication};
 use blocky_core::gobject::GInstance;
 use blocky_core::instance::Instance;
+use blocky_core::minecraft::install_state::InstallState;
 use blocky_core::minecraft::installation_update::InstallationUpdate;
+use blocky_core::minecraft::verify_report::VerifyReport;
+use blocky_core::modpack::curseforge::CurseForgeReport;
+use gettextrs::gettext;
 use gio::prelude::*;
 use gio::ListStore;
 use glib::subclass::prelude::*;
@@ -13,6 +17,7 @@ use glib::{
     Value,
 };
 use once_cell::sync::Lazy;
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::thread;
@@ -270,14 +275,77 @@ impl BlockyInstanceManager {
         g_receiver
     }
 
+    pub fn repair_instance(&self, uuid: Uuid) -> glib::Receiver<InstallationUpdate> {
+        info!("Repairing instance '{}'", &uuid);
+        let imp = imp::BlockyInstanceManager::from_instance(self);
+        imp.cancel_current_installation
+            .store(false, Ordering::Relaxed);
+
+        let (g_sender, g_receiver) = glib::MainContext::channel(glib::PRIORITY_DEFAULT);
+        let path = settings::get_string(SettingKey::InstancesFilePath);
+
+        let cancel_flag = imp.cancel_current_installation.clone();
+        thread::spawn(move || {
+            let receiver = helpers::repair_threaded(uuid, path.clone(), cancel_flag);
+
+            while let Ok(update) = receiver.recv() {
+                g_sender
+                    .send(update)
+                    .expect("Could not send update through channel");
+            }
+        });
+
+        g_receiver
+    }
+
+    // The report is received after `InstallationUpdate::Success`
+    pub fn install_modpack(
+        &self,
+        instance: Instance,
+        pack_path: PathBuf,
+    ) -> (
+        glib::Receiver<InstallationUpdate>,
+        glib::Receiver<CurseForgeReport>,
+    ) {
+        info!("Installing modpack into instance '{}'", &instance.uuid);
+        let imp = imp::BlockyInstanceManager::from_instance(self);
+        imp.cancel_current_installation
+            .store(false, Ordering::Relaxed);
+
+        let (g_sender, g_receiver) = glib::MainContext::channel(glib::PRIORITY_DEFAULT);
+        let (g_report_sender, g_report_receiver) =
+            glib::MainContext::channel(glib::PRIORITY_DEFAULT);
+
+        let cancel_flag = imp.cancel_current_installation.clone();
+        thread::spawn(move || {
+            let (receiver, report_receiver) =
+                helpers::install_modpack_threaded(instance, pack_path, cancel_flag);
+
+            while let Ok(update) = receiver.recv() {
+                g_sender
+                    .send(update)
+                    .expect("Could not send update through channel");
+            }
+            if let Ok(report) = report_receiver.recv() {
+                g_report_sender
+                    .send(report)
+                    .expect("Could not send report through channel");
+            }
+        });
+
+        (g_receiver, g_report_receiver)
+    }
+
     pub fn cancel_current_installation(&self) {
         let imp = imp::BlockyInstanceManager::from_instance(self);
         imp.cancel_current_installation
             .store(true, Ordering::Relaxed);
     }
 
-    pub fn launch_instance(&self, uuid: Uuid) {
+    // Receives a message if the instance could not be launched
+    pub fn launch_instance(&self, uuid: Uuid) -> glib::Receiver<String> {
         info!("Launching instance '{}'", &uuid);
+        let (g_sender, g_receiver) = glib::MainContext::channel(glib::PRIORITY_DEFAULT);
         let instances_path = settings::get_string(SettingKey::InstancesFilePath);
         let profiles_path = settings::get_string(SettingKey::ProfilesFilePath);
 
@@ -286,6 +354,9 @@ impl BlockyInstanceManager {
             let current_profile = BlockyProfileManager::default().current_profile();
             if current_profile.is_none() {
                 error!("No profile selected");
+                g_sender
+                    .send(gettext("No profile selected"))
+                    .expect("Could not send error through channel");
                 return;
             }
             let profile_uuid = current_profile.unwrap().uuid();
@@ -294,6 +365,9 @@ impl BlockyInstanceManager {
                 build_launch_options(uuid, instances_path.clone(), profile_uuid, profiles_path);
             if let Err(err) = launch_options {
                 error!("Error while building launch options: {}", err);
+                g_sender
+                    .send(err.to_string())
+                    .expect("Could not send error through channel");
                 return;
             }
 
@@ -301,24 +375,52 @@ impl BlockyInstanceManager {
 
             if let Err(err) = launch_result {
                 error!("Error while launching instance: {}", err);
+                g_sender
+                    .send(err.to_string())
+                    .expect("Could not send error through channel");
             }
         });
+
+        g_receiver
+    }
+
+    // Receives `None` if the files could not be checked
+    pub fn verify_instance(&self, uuid: Uuid) -> glib::Receiver<Option<VerifyReport>> {
+        info!("Verifying instance '{}'", &uuid);
+        let (g_sender, g_receiver) = glib::MainContext::channel(glib::PRIORITY_DEFAULT);
+        let path = settings::get_string(SettingKey::InstancesFilePath);
+
+        thread::spawn(move || match helpers::verify(uuid, path) {
+            Ok(report) => {
+                g_sender
+                    .send(Some(report))
+                    .expect("Could not send report through channel");
+            }
+            Err(err) => {
+                error!("Error while verifying instance: {}", err);
+                g_sender
+                    .send(None)
+                    .expect("Could not send report through channel");
+            }
+        });
+
+        g_receiver
     }
 
-    pub fn check_instance_installed(&self, uuid: Uuid) -> glib::Receiver<bool> {
+    pub fn check_instance_installed(&self, uuid: Uuid) -> glib::Receiver<InstallState> {
         let (g_sender, g_receiver) = glib::MainContext::channel(glib::PRIORITY_DEFAULT);
         let path = settings::get_string(SettingKey::InstancesFilePath);
 
         thread::spawn(move || match helpers::check_install_state(uuid, path) {
-            Ok(installed) => {
+            Ok(install_state) => {
                 g_sender
-                    .send(installed)
+                    .send(install_state)
                     .expect("Could not send status through channel");
             }
             Err(err) => {
                 error!("Error while checking installed state: {}", err);
                 g_sender
-                    .send(false)
+                    .send(InstallState::NotInstalled)
                     .expect("Could not send status through channel");
             }
         });
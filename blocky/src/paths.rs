@@ -21,6 +21,12 @@ lazy_static! {
         path.push(config::PKG_NAME);
         path
     };
+    pub static ref JAVA_RUNTIMES: PathBuf = {
+        let mut path = glib::user_data_dir();
+        path.push(config::PKG_NAME);
+        path.push("runtimes");
+        path
+    };
     static ref DEFAULT_INSTANCES_DIR: PathBuf = {
         let mut path = glib::user_data_dir();
         path.push(config::PKG_NAME);
@@ -39,6 +45,12 @@ lazy_static! {
         path.push("assets");
         path
     };
+    static ref DEFAULT_STORE_DIR: PathBuf = {
+        let mut path = glib::user_data_dir();
+        path.push(config::PKG_NAME);
+        path.push("store");
+        path
+    };
     static ref PROFILES_FILE_PATH: PathBuf = {
         let mut path = glib::user_data_dir();
         path.push(config::PKG_NAME);
@@ -87,6 +99,11 @@ pub fn set_defaults() {
         settings::set_string(SettingKey::AssetsDir, &DEFAULT_ASSETS_DIR.to_string_lossy());
     }
 
+    let default_store_dir = settings::get_string(SettingKey::StoreDir);
+    if default_store_dir == "NULL" {
+        settings::set_string(SettingKey::StoreDir, &DEFAULT_STORE_DIR.to_string_lossy());
+    }
+
     let profiles_file_path = settings::get_string(SettingKey::ProfilesFilePath);
     if profiles_file_path == "NULL" {
         settings::set_string(
@@ -8,6 +8,7 @@ pub enum SettingKey {
     InstancesDir,
     LibrariesDir,
     AssetsDir,
+    StoreDir,
     UseFullscreen,
     EnableWindowSize,
     GameWindowWidth,
@@ -15,9 +16,23 @@ pub enum SettingKey {
     EnableMemory,
     MinMemory,
     MaxMemory,
+    UseManagedJava,
     JavaExec,
     EnableJvmArgs,
     JvmArgs,
+    // Endpoints
+    VersionManifestUrl,
+    LibrariesUrl,
+    AssetsUrl,
+    JavaRuntimesUrl,
+    UrlRewrites,
+    ModrinthApiUrl,
+    CurseForgeApiUrl,
+    CurseForgeApiKey,
+    // Downloads
+    DownloadWorkers,
+    EnableStore,
+    MetadataMaxAge,
     // Storage
     ProfilesFilePath,
     InstancesFilePath,
@@ -34,6 +49,7 @@ impl SettingKey {
             SettingKey::InstancesDir => "instances-dir",
             SettingKey::LibrariesDir => "libraries-dir",
             SettingKey::AssetsDir => "assets-dir",
+            SettingKey::StoreDir => "store-dir",
             SettingKey::UseFullscreen => "use-fullscreen",
             SettingKey::EnableWindowSize => "enable-window-size",
             SettingKey::GameWindowWidth => "game-window-width",
@@ -41,9 +57,21 @@ impl SettingKey {
             SettingKey::EnableMemory => "enable-memory",
             SettingKey::MinMemory => "min-memory",
             SettingKey::MaxMemory => "max-memory",
+            SettingKey::UseManagedJava => "use-managed-java",
             SettingKey::JavaExec => "java-exec",
             SettingKey::EnableJvmArgs => "enable-jvm-args",
             SettingKey::JvmArgs => "jvm-args",
+            SettingKey::VersionManifestUrl => "version-manifest-url",
+            SettingKey::LibrariesUrl => "libraries-url",
+            SettingKey::AssetsUrl => "assets-url",
+            SettingKey::JavaRuntimesUrl => "java-runtimes-url",
+            SettingKey::UrlRewrites => "url-rewrites",
+            SettingKey::ModrinthApiUrl => "modrinth-api-url",
+            SettingKey::CurseForgeApiUrl => "curseforge-api-url",
+            SettingKey::CurseForgeApiKey => "curseforge-api-key",
+            SettingKey::DownloadWorkers => "download-workers",
+            SettingKey::EnableStore => "enable-store",
+            SettingKey::MetadataMaxAge => "metadata-max-age",
             SettingKey::ProfilesFilePath => "profiles-file-path",
             SettingKey::InstancesFilePath => "instances-file-path",
             SettingKey::DefaultProfile => "default-profile",
@@ -77,6 +105,15 @@ pub fn set_string(key: SettingKey, value: &str) {
     setting.set_string(key.to_key(), value).unwrap();
 }
 
+pub fn get_strings(key: SettingKey) -> Vec<String> {
+    let settings = get_settings();
+    settings
+        .strv(key.to_key())
+        .iter()
+        .map(|value| value.to_string())
+        .collect()
+}
+
 pub fn get_bool(key: SettingKey) -> bool {
     let settings = get_settings();
     settings.boolean(key.to_key())
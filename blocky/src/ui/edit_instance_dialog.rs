@@ -1,9 +1,16 @@
+use crate::helpers;
 use crate::managers::BlockyInstanceManager;
 use crate::ui::BlockyApplicationWindow;
+use crate::utils::java::fetch_java;
+use crate::utils::modrinth::{fetch_installed_mods, fetch_mod_search, install_mod, resolve_mod};
 use adw::prelude::*;
 use blocky_core::gobject::instance;
 use blocky_core::gobject::GInstance;
 use blocky_core::instance::Instance;
+use blocky_core::java::models::java_installation::JavaCompatibility;
+use blocky_core::modrinth::models::installed_mod::InstalledMod;
+use blocky_core::modrinth::models::project::SearchHit;
+use blocky_core::modrinth::resolve::Resolution;
 use gettextrs::gettext;
 use glib::subclass::prelude::*;
 use glib::subclass::InitializingObject;
@@ -71,8 +78,17 @@ mod imp {
         #[template_child]
         pub window_height_spinbutton: TemplateChild<gtk::SpinButton>,
 
+        // Mods
+        #[template_child]
+        pub installed_mods_list: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub mods_search_entry: TemplateChild<gtk::SearchEntry>,
+        #[template_child]
+        pub mod_results_list: TemplateChild<gtk::ListBox>,
+
         pub instance: OnceCell<GInstance>,
         pub name_valid: Cell<bool>,
+        pub java_discovered: Cell<bool>,
     }
 
     #[glib::object_subclass]
@@ -172,6 +188,31 @@ impl BlockyEditInstanceDialog {
 
         self.update_save_button();
     }
+
+    #[template_callback]
+    fn search_mods(&self) {
+        let imp = imp::BlockyEditInstanceDialog::from_instance(self);
+        let query = imp.mods_search_entry.text().to_string();
+
+        let instance = Instance::from(self.instance());
+        fetch_mod_search(instance, query).attach(
+            None,
+            glib::clone!(@weak self as this => @default-return glib::Continue(false),
+                move |hits| {
+                    match hits {
+                        Ok(hits) => this.show_mod_results(hits),
+                        Err(err) => {
+                            let imp = imp::BlockyEditInstanceDialog::from_instance(&this);
+                            clear_list(&imp.mod_results_list);
+                            let window = BlockyApplicationWindow::default();
+                            window.toast_notification(&format!("{}: {}", gettext("Searching mods failed"), err));
+                        }
+                    }
+                    glib::Continue(true)
+                }
+            ),
+        );
+    }
 }
 
 impl BlockyEditInstanceDialog {
@@ -192,6 +233,18 @@ impl BlockyEditInstanceDialog {
         for view in View::iter() {
             let row = view.create_list_row();
             imp.pages_list.append(&row);
+
+            // Mods can only be managed for instances with a loader
+            if let View::Mods = view {
+                self.instance()
+                    .bind_property(instance::LOADER, &row, "visible")
+                    .transform_to(|_, value| {
+                        let loader = value.get::<String>().unwrap_or_default();
+                        Some((!loader.is_empty()).to_value())
+                    })
+                    .flags(glib::BindingFlags::SYNC_CREATE)
+                    .build();
+            }
         }
 
         // General
@@ -335,8 +388,9 @@ impl BlockyEditInstanceDialog {
         // Setup for view
         match view {
             View::General => {}
-            View::Java => {}
+            View::Java => self.discover_java(),
             View::Game => {}
+            View::Mods => self.refresh_installed_mods(),
             View::Saves => {}
             View::Servers => {}
             View::ScreenShots => {}
@@ -346,6 +400,190 @@ impl BlockyEditInstanceDialog {
         imp.stack.set_visible_child_name(view.get_id())
     }
 
+    fn discover_java(&self) {
+        let imp = imp::BlockyEditInstanceDialog::from_instance(self);
+        if imp.java_discovered.replace(true) {
+            return;
+        }
+
+        let instance = Instance::from(self.instance());
+        fetch_java(instance).attach(
+            None,
+            glib::clone!(@weak self as this => @default-return glib::Continue(false),
+                move |installations| {
+                    let imp = imp::BlockyEditInstanceDialog::from_instance(&this);
+
+                    for (installation, compatibility) in installations {
+                        let path = installation.java_exec.to_string_lossy().to_string();
+
+                        let row = adw::ActionRow::new();
+                        row.set_title(&installation.display_name());
+                        row.set_subtitle(&path);
+                        if compatibility == Some(JavaCompatibility::Newer) {
+                            let warning = gtk::Image::builder()
+                                .icon_name("dialog-warning-symbolic")
+                                .tooltip_text(&gettext("Newer than the required Java, might be incompatible"))
+                                .build();
+                            row.add_suffix(&warning);
+                        }
+                        row.set_activatable(true);
+                        row.connect_activated(glib::clone!(@weak this => move |_| {
+                            let imp = imp::BlockyEditInstanceDialog::from_instance(&this);
+                            imp.java_exec_label.set_label(&path);
+                        }));
+
+                        imp.override_java_exec_expander.add_row(&row);
+                    }
+
+                    glib::Continue(true)
+                }
+            ),
+        );
+    }
+
+    fn refresh_installed_mods(&self) {
+        let instance = Instance::from(self.instance());
+        fetch_installed_mods(instance).attach(
+            None,
+            glib::clone!(@weak self as this => @default-return glib::Continue(false),
+                move |mods| {
+                    this.show_installed_mods(mods);
+                    glib::Continue(true)
+                }
+            ),
+        );
+    }
+
+    fn show_installed_mods(&self, mods: Vec<InstalledMod>) {
+        let imp = imp::BlockyEditInstanceDialog::from_instance(self);
+        clear_list(&imp.installed_mods_list);
+
+        for installed in mods {
+            let row = adw::ActionRow::new();
+            match &installed.version {
+                Some(version) => {
+                    row.set_title(&version.name);
+                    row.set_subtitle(&installed.file_name);
+                }
+                None => row.set_title(&installed.file_name),
+            }
+
+            let remove_button = gtk::Button::builder()
+                .icon_name("user-trash-symbolic")
+                .tooltip_text(&gettext("Remove"))
+                .valign(gtk::Align::Center)
+                .css_classes(vec!["flat".to_string()])
+                .build();
+            let file_name = installed.file_name.clone();
+            remove_button.connect_clicked(glib::clone!(@weak self as this => move |_| {
+                let instance = Instance::from(this.instance());
+                if let Err(err) = helpers::remove_mod(&instance, &file_name) {
+                    error!("Error while removing mod: {}", err);
+                    let window = BlockyApplicationWindow::default();
+                    window.toast_notification(&format!("{}: {}", gettext("Removing mod failed"), err));
+                }
+                this.refresh_installed_mods();
+            }));
+            row.add_suffix(&remove_button);
+
+            imp.installed_mods_list.append(&row);
+        }
+    }
+
+    fn show_mod_results(&self, hits: Vec<SearchHit>) {
+        let imp = imp::BlockyEditInstanceDialog::from_instance(self);
+        clear_list(&imp.mod_results_list);
+
+        for hit in hits {
+            let row = adw::ActionRow::new();
+            row.set_title(&hit.title);
+            row.set_subtitle(&hit.description);
+
+            let install_button = gtk::Button::builder()
+                .label(&gettext("Install"))
+                .valign(gtk::Align::Center)
+                .build();
+            let project_id = hit.project_id.clone();
+            install_button.connect_clicked(glib::clone!(@weak self as this => move |button| {
+                button.set_sensitive(false);
+                this.resolve_mod(project_id.clone(), button.clone());
+            }));
+            row.add_suffix(&install_button);
+
+            imp.mod_results_list.append(&row);
+        }
+    }
+
+    fn resolve_mod(&self, project_id: String, button: gtk::Button) {
+        let instance = Instance::from(self.instance());
+        resolve_mod(instance, project_id).attach(
+            None,
+            glib::clone!(@weak self as this => @default-return glib::Continue(false),
+                move |resolution| {
+                    match resolution {
+                        Some(resolution) if resolution.warnings.is_empty() => {
+                            this.install_mod(resolution, button.clone());
+                        }
+                        Some(resolution) => this.confirm_mod_warnings(resolution, button.clone()),
+                        None => {
+                            let window = BlockyApplicationWindow::default();
+                            window.toast_notification(&gettext("Installing mod failed."));
+                            button.set_sensitive(true);
+                        }
+                    }
+                    glib::Continue(true)
+                }
+            ),
+        );
+    }
+
+    // Incompatibilities and missing dependencies are shown before anything gets installed
+    fn confirm_mod_warnings(&self, resolution: Resolution, button: gtk::Button) {
+        let dialog = gtk::MessageDialog::builder()
+            .transient_for(self)
+            .modal(true)
+            .message_type(gtk::MessageType::Warning)
+            .text(&gettext("Install mod anyway?"))
+            .secondary_text(&resolution.warnings.join("\n"))
+            .build();
+        dialog.add_buttons(&[
+            (gettext("Cancel").as_str(), ResponseType::Cancel),
+            (gettext("Install").as_str(), ResponseType::Accept),
+        ]);
+
+        dialog.connect_response(glib::clone!(@weak self as this => move |dialog, resp| {
+            dialog.close();
+            if resp == ResponseType::Accept {
+                this.install_mod(resolution.clone(), button.clone());
+            } else {
+                button.set_sensitive(true);
+            }
+        }));
+
+        dialog.show();
+    }
+
+    fn install_mod(&self, resolution: Resolution, button: gtk::Button) {
+        let instance = Instance::from(self.instance());
+        install_mod(instance, resolution).attach(
+            None,
+            glib::clone!(@weak self as this => @default-return glib::Continue(false),
+                move |installed| {
+                    let window = BlockyApplicationWindow::default();
+                    if installed {
+                        window.toast_notification(&gettext("Mod installed."));
+                    } else {
+                        window.toast_notification(&gettext("Installing mod failed."));
+                    }
+
+                    button.set_sensitive(true);
+                    this.refresh_installed_mods();
+                    glib::Continue(true)
+                }
+            ),
+        );
+    }
+
     pub fn instance(&self) -> GInstance {
         self.property("instance")
     }
@@ -363,11 +601,18 @@ impl BlockyEditInstanceDialog {
     }
 }
 
+fn clear_list(list: &gtk::ListBox) {
+    while let Some(child) = list.first_child() {
+        list.remove(&child);
+    }
+}
+
 #[derive(Clone, Debug, EnumIter)]
 enum View {
     General,
     Java,
     Game,
+    Mods,
     Saves,
     Servers,
     ScreenShots,
@@ -380,6 +625,7 @@ impl From<&str> for View {
             "general" => Self::General,
             "java" => Self::Java,
             "game" => Self::Game,
+            "mods" => Self::Mods,
             "saves" => Self::Saves,
             "servers" => Self::Servers,
             "screenshots" => Self::ScreenShots,
@@ -413,6 +659,7 @@ impl View {
             View::General => "general",
             View::Java => "java",
             View::Game => "game",
+            View::Mods => "mods",
             View::Saves => "saves",
             View::Servers => "servers",
             View::ScreenShots => "screenshots",
@@ -425,6 +672,7 @@ impl View {
             View::General => "General",
             View::Java => "Java",
             View::Game => "Game",
+            View::Mods => "Mods",
             View::Saves => "Saves",
             View::Servers => "Servers",
             View::ScreenShots => "Screenshots",
@@ -6,6 +6,7 @@ use gtk::prelude::*;
 use gtk::subclass::prelude::*;
 use gtk::CompositeTemplate;
 use std::str::FromStr;
+use std::time::Duration;
 use uuid::Uuid;
 
 mod imp {
@@ -20,6 +21,8 @@ mod imp {
         pub progress_bar: TemplateChild<gtk::ProgressBar>,
         #[template_child]
         pub status_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub details_label: TemplateChild<gtk::Label>,
     }
 
     #[glib::object_subclass]
@@ -92,11 +95,13 @@ impl BlockyInstallProgressDialog {
             InstallationUpdate::Asset(progress) => progress,
             InstallationUpdate::LogConfig(progress) => progress,
             InstallationUpdate::Client(progress) => progress,
+            InstallationUpdate::JavaRuntime(progress) => progress,
+            InstallationUpdate::Loader(progress) => progress,
+            InstallationUpdate::Modpack(progress) => progress,
             _ => unreachable!(),
         };
 
-        let fraction = progress.current_file as f64 / progress.total_files as f64;
-        imp.progress_bar.set_fraction(fraction);
+        imp.progress_bar.set_fraction(progress.fraction());
         imp.spinner.start();
 
         imp.status_label.set_label(&format!(
@@ -105,6 +110,22 @@ impl BlockyInstallProgressDialog {
             progress.current_file,
             progress.total_files
         ));
+
+        let mut details = format!(
+            "{} of {}",
+            glib::format_size(progress.downloaded_bytes),
+            glib::format_size(progress.total_bytes)
+        );
+        if progress.speed > 0.0 {
+            details.push_str(&format!(
+                " ({}/s)",
+                glib::format_size(progress.speed as u64)
+            ));
+        }
+        if let Some(eta) = progress.eta {
+            details.push_str(&format!(" - {}", format_eta(eta)));
+        }
+        imp.details_label.set_label(&details);
     }
 
     pub fn uuid(&self) -> Uuid {
@@ -112,3 +133,17 @@ impl BlockyInstallProgressDialog {
         Uuid::from_str(&uuid).unwrap()
     }
 }
+
+fn format_eta(eta: Duration) -> String {
+    let seconds = eta.as_secs();
+
+    match seconds {
+        0..=59 => format!("{} s remaining", seconds),
+        60..=3599 => format!("{} min {} s remaining", seconds / 60, seconds % 60),
+        _ => format!(
+            "{} h {} min remaining",
+            seconds / 3600,
+            (seconds % 3600) / 60
+        ),
+    }
+}
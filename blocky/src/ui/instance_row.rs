@@ -3,7 +3,9 @@ use crate::ui::edit_instance_dialog::BlockyEditInstanceDialog;
 use crate::ui::{BlockyApplicationWindow, BlockyInstallProgressDialog};
 use blocky_core::gobject::GInstance;
 use blocky_core::instance::Instance;
+use blocky_core::minecraft::install_state::InstallState;
 use blocky_core::minecraft::installation_update::InstallationUpdate;
+use blocky_core::minecraft::verify_report::VerifyReport;
 use gettextrs::gettext;
 use glib::subclass::InitializingObject;
 use glib::ToValue;
@@ -161,6 +163,38 @@ impl BlockyInstanceRow {
         self.property("instance")
     }
 
+    // Main button either launches the instance or (continues to) install it
+    pub fn set_install_state(&self, install_state: &InstallState) {
+        let imp = imp::BlockyInstanceRow::from_instance(self);
+
+        let (action, icon, tooltip) = match install_state {
+            InstallState::Installed => (
+                "instance.launch",
+                "media-playback-start-symbolic",
+                gettext("Launch Instance"),
+            ),
+            InstallState::NotInstalled => (
+                "instance.install",
+                "folder-download-symbolic",
+                gettext("Install Instance"),
+            ),
+            InstallState::Partial(_) => (
+                "instance.install",
+                "folder-download-symbolic",
+                gettext("Resume Installation"),
+            ),
+            InstallState::Outdated => (
+                "instance.install",
+                "view-refresh-symbolic",
+                gettext("Update Instance"),
+            ),
+        };
+
+        imp.launch_button.set_action_name(Some(action));
+        imp.launch_button.set_icon_name(icon);
+        imp.launch_button.set_tooltip_text(Some(&tooltip));
+    }
+
     fn bind_property<T: IsA<gtk::Widget>>(
         &self,
         prop_name: &str,
@@ -185,37 +219,56 @@ fn install_actions(instance: &GInstance, widget: gtk::Widget) {
     launch_action.connect_activate(
         glib::clone!(@weak instance, @weak instance_manager, @weak window => move |_, _| {
             window.toast_notification(&gettext("Launching instance."));
-            instance_manager.launch_instance(instance.uuid());
+            instance_manager.launch_instance(instance.uuid()).attach(
+                None,
+                glib::clone!(@weak window => @default-return glib::Continue(false), move |msg| {
+                    window.toast_notification(&format!("{}: {}", gettext("Launch failed"), msg));
+                    glib::Continue(true)
+                }),
+            );
         }),
     );
     launch_action.set_enabled(false);
     actions.add_action(&launch_action);
 
-    instance_manager.check_instance_installed(instance.uuid()).attach(
-        None,
-        glib::clone!(@weak launch_action => @default-return glib::Continue(false), move |status| {
-            launch_action.set_enabled(status);
-            glib::Continue(true)
-        }
-    ));
+    let row = widget.downcast::<BlockyInstanceRow>().unwrap();
+    check_install_state(&row, &launch_action);
 
     // instance.install
     let install_action = gio::SimpleAction::new("install", None);
-    install_action.connect_activate(glib::clone!(@weak instance, @weak launch_action, @weak instance_manager => move |_, _| {
+    install_action.connect_activate(glib::clone!(@weak instance, @weak row, @weak launch_action, @weak instance_manager => move |_, _| {
         let dialog = BlockyInstallProgressDialog::new();
         dialog.show();
 
         let receiver = instance_manager.install_instance(instance.uuid());
         receiver.attach(
             None,
-            glib::clone!(@weak dialog, @weak launch_action => @default-return glib::Continue(false), move |update| {
-                process_install_update(update, dialog.upcast(), launch_action);
+            glib::clone!(@weak dialog, @weak row, @weak launch_action => @default-return glib::Continue(false), move |update| {
+                process_install_update(update, dialog.upcast(), &row, &launch_action);
                 glib::Continue(true)
             }
         ));
     }));
     actions.add_action(&install_action);
 
+    // instance.verify
+    let verify_action = gio::SimpleAction::new("verify", None);
+    verify_action.connect_activate(glib::clone!(@weak instance, @weak row, @weak launch_action, @weak instance_manager, @weak window => move |_, _| {
+        let receiver = instance_manager.verify_instance(instance.uuid());
+        receiver.attach(
+            None,
+            glib::clone!(@weak row, @weak launch_action, @weak window => @default-return glib::Continue(false), move |report| {
+                match report {
+                    None => window.toast_notification(&gettext("Verifying files failed.")),
+                    Some(report) if report.is_intact() => window.toast_notification(&gettext("All files are intact.")),
+                    Some(report) => show_verify_report(&report, &row, &launch_action),
+                }
+                glib::Continue(true)
+            }
+        ));
+    }));
+    actions.add_action(&verify_action);
+
     // instance.edit
     let edit_action = gio::SimpleAction::new("edit", None);
     edit_action.connect_activate(glib::clone!(@weak instance => move |_, _| {
@@ -237,22 +290,124 @@ fn install_actions(instance: &GInstance, widget: gtk::Widget) {
     actions.add_action(&remove_action);
 }
 
+// Repairs the instance only if the user confirms
+fn show_verify_report(
+    report: &VerifyReport,
+    row: &BlockyInstanceRow,
+    launch_action: &gio::SimpleAction,
+) {
+    let window = BlockyApplicationWindow::default();
+    let dialog = gtk::MessageDialog::builder()
+        .transient_for(&window)
+        .modal(true)
+        .message_type(gtk::MessageType::Question)
+        .text(&gettext("Some files are broken"))
+        .secondary_text(&verify_report_text(report))
+        .build();
+    dialog.add_buttons(&[
+        (gettext("Cancel").as_str(), gtk::ResponseType::Cancel),
+        (gettext("Repair").as_str(), gtk::ResponseType::Accept),
+    ]);
+
+    dialog.connect_response(
+        glib::clone!(@weak row, @weak launch_action => move |dialog, response| {
+            dialog.close();
+            if response != gtk::ResponseType::Accept {
+                return;
+            }
+
+            let instance_manager = BlockyInstanceManager::default();
+            let progress_dialog = BlockyInstallProgressDialog::new();
+            progress_dialog.show();
+
+            let receiver = instance_manager.repair_instance(row.instance().uuid());
+            receiver.attach(
+                None,
+                glib::clone!(@weak progress_dialog, @weak row, @weak launch_action => @default-return glib::Continue(false), move |update| {
+                    process_install_update(update, progress_dialog.upcast(), &row, &launch_action);
+                    glib::Continue(true)
+                }
+            ));
+        }),
+    );
+
+    dialog.show();
+}
+
+fn verify_report_text(report: &VerifyReport) -> String {
+    const MAX_FILES: usize = 10;
+
+    let mut text = format!(
+        "{}: {}\n{}: {}\n{}: {}\n{}: {}",
+        gettext("Missing"),
+        report.missing.len(),
+        gettext("Wrong size"),
+        report.size_mismatch.len(),
+        gettext("Wrong checksum"),
+        report.hash_mismatch.len(),
+        gettext("Leftover files"),
+        report.extraneous.len()
+    );
+
+    let files = report
+        .missing
+        .iter()
+        .chain(report.size_mismatch.iter())
+        .chain(report.hash_mismatch.iter())
+        .chain(report.extraneous.iter())
+        .collect::<Vec<_>>();
+    text.push('\n');
+    for file in files.iter().take(MAX_FILES) {
+        text.push_str(&format!("\n{}", file.to_string_lossy()));
+    }
+    if files.len() > MAX_FILES {
+        text.push_str(&format!(
+            "\n+{} {}",
+            files.len() - MAX_FILES,
+            gettext("more")
+        ));
+    }
+
+    text
+}
+
+fn check_install_state(row: &BlockyInstanceRow, launch_action: &gio::SimpleAction) {
+    let instance_manager = BlockyInstanceManager::default();
+
+    instance_manager.check_instance_installed(row.instance().uuid()).attach(
+        None,
+        glib::clone!(@weak row, @weak launch_action => @default-return glib::Continue(false), move |install_state| {
+            launch_action.set_enabled(install_state == InstallState::Installed);
+            row.set_install_state(&install_state);
+            glib::Continue(true)
+        }
+    ));
+}
+
 fn process_install_update(
     update: InstallationUpdate,
     dialog: gtk::Dialog,
-    launch_action: gio::SimpleAction,
+    row: &BlockyInstanceRow,
+    launch_action: &gio::SimpleAction,
 ) {
     match update {
         InstallationUpdate::Success => {
             // Update finished
             let window = BlockyApplicationWindow::default();
-            launch_action.set_enabled(true);
+            check_install_state(row, launch_action);
             dialog.close();
             window.toast_notification(&gettext("Installation finished."));
         }
         InstallationUpdate::Cancel => {
             // Update finished
+            check_install_state(row, launch_action);
+            dialog.close();
+        }
+        InstallationUpdate::Failed(msg) => {
+            let window = BlockyApplicationWindow::default();
+            check_install_state(row, launch_action);
             dialog.close();
+            window.toast_notification(&format!("{}: {}", gettext("Installation failed"), msg));
         }
         update => {
             let dialog = dialog.downcast::<BlockyInstallProgressDialog>().unwrap();
@@ -5,6 +5,7 @@ mod install_progress_dialog;
 mod instance_group;
 mod instance_page;
 mod instance_row;
+pub mod modpack_import;
 mod new_instance_dialog;
 mod new_profile_dialog;
 mod preferences_window;
@@ -0,0 +1,122 @@
+use crate::helpers;
+use crate::managers::BlockyInstanceManager;
+use crate::ui::{BlockyApplicationWindow, BlockyInstallProgressDialog};
+use blocky_core::minecraft::installation_update::InstallationUpdate;
+use blocky_core::modpack::curseforge::CurseForgeReport;
+use gettextrs::gettext;
+use gtk::prelude::*;
+use gtk::{FileChooserAction, FileChooserNative, FileFilter, ResponseType};
+use std::path::PathBuf;
+
+pub fn show_import_dialog() {
+    let main_window = BlockyApplicationWindow::default();
+
+    let filter = FileFilter::new();
+    filter.set_name(Some(&gettext("Modpacks")));
+    filter.add_pattern("*.mrpack");
+    filter.add_pattern("*.zip");
+
+    let dialog = FileChooserNative::new(
+        Some(&gettext("Import Modpack")),
+        Some(&main_window),
+        FileChooserAction::Open,
+        Some(&gettext("Import")),
+        Some(&gettext("Cancel")),
+    );
+    dialog.add_filter(&filter);
+
+    dialog.connect_response(glib::clone!(@strong dialog => move |_, resp| {
+        dialog.destroy();
+        if resp == ResponseType::Accept {
+            if let Some(path) = dialog.file().and_then(|file| file.path()) {
+                debug!("Selected modpack: {}", path.to_string_lossy());
+                import_modpack(path);
+            }
+        }
+    }));
+
+    dialog.show();
+}
+
+fn import_modpack(pack_path: PathBuf) {
+    let window = BlockyApplicationWindow::default();
+    let instance_manager = BlockyInstanceManager::default();
+
+    let instance = match helpers::create_modpack_instance(&pack_path) {
+        Ok(instance) => instance,
+        Err(err) => {
+            error!("Error while reading modpack - {}", err);
+            window.toast_notification(&format!("{}: {}", gettext("Import failed"), err));
+            return;
+        }
+    };
+    instance_manager.add_instance(instance.clone());
+
+    let dialog = BlockyInstallProgressDialog::new();
+    dialog.show();
+
+    let (receiver, report_receiver) = instance_manager.install_modpack(instance.clone(), pack_path);
+    receiver.attach(
+        None,
+        glib::clone!(@weak dialog, @weak window, @weak instance_manager => @default-return glib::Continue(false), move |update| {
+            match update {
+                InstallationUpdate::Success => {
+                    // Refreshes the install state of the row
+                    instance_manager.update_instance(instance.clone());
+                    dialog.close();
+                }
+                InstallationUpdate::Cancel => {
+                    dialog.close();
+                }
+                InstallationUpdate::Failed(msg) => {
+                    dialog.close();
+                    window.toast_notification(&format!("{}: {}", gettext("Import failed"), msg));
+                }
+                update => dialog.update_widgets(update),
+            }
+            glib::Continue(true)
+        }),
+    );
+    report_receiver.attach(
+        None,
+        glib::clone!(@weak window => @default-return glib::Continue(false), move |report| {
+            if report.unresolved.is_empty() {
+                window.toast_notification(&gettext("Modpack imported."));
+            } else {
+                show_unresolved_files(&report);
+            }
+            glib::Continue(true)
+        }),
+    );
+}
+
+fn show_unresolved_files(report: &CurseForgeReport) {
+    let window = BlockyApplicationWindow::default();
+
+    let files = report
+        .unresolved
+        .iter()
+        .map(|file| {
+            format!(
+                "{} {} ({} {}): {}",
+                gettext("Project"),
+                file.project_id,
+                gettext("file"),
+                file.file_id,
+                &file.reason
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let dialog = gtk::MessageDialog::builder()
+        .transient_for(&window)
+        .modal(true)
+        .message_type(gtk::MessageType::Warning)
+        .buttons(gtk::ButtonsType::Close)
+        .text(&gettext("Modpack imported with skipped files"))
+        .secondary_text(&files)
+        .build();
+    dialog.connect_response(|dialog, _| dialog.close());
+    dialog.show();
+}
@@ -2,9 +2,11 @@ use crate::managers::BlockyInstanceManager;
 use crate::settings;
 use crate::settings::SettingKey;
 use crate::ui::BlockyApplicationWindow;
+use crate::utils::loader::fetch_loader_versions;
 use crate::utils::version_summary::{fetch_manifest, filter_versions, version_list_factory};
 use adw::prelude::*;
 use blocky_core::gobject::GVersionSummary;
+use blocky_core::loader::models::loader::{Loader, LoaderKind};
 use blocky_core::minecraft::models::version_summary::VersionSummary;
 use gettextrs::gettext;
 use gio::ListStore;
@@ -53,6 +55,24 @@ mod imp {
         #[template_child]
         pub alphas_filter_switch: TemplateChild<gtk::Switch>,
 
+        // Mod Loader
+        #[template_child]
+        pub fabric_expander: TemplateChild<adw::ExpanderRow>,
+        #[template_child]
+        pub fabric_version_list: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub forge_expander: TemplateChild<adw::ExpanderRow>,
+        #[template_child]
+        pub forge_version_list: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub quilt_expander: TemplateChild<adw::ExpanderRow>,
+        #[template_child]
+        pub quilt_version_list: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub neoforge_expander: TemplateChild<adw::ExpanderRow>,
+        #[template_child]
+        pub neoforge_version_list: TemplateChild<gtk::ListBox>,
+
         // Advanced
         #[template_child]
         pub instance_dir_button: TemplateChild<gtk::Button>,
@@ -71,9 +91,11 @@ mod imp {
         pub manifest: RefCell<HashMap<String, VersionSummary>>,
         pub version_list_store: ListStore,
         pub version_selection_model: SingleSelection,
+        pub loader_versions: RefCell<HashMap<LoaderKind, Vec<String>>>,
 
         pub name_valid: Cell<bool>,
         pub version_valid: Cell<bool>,
+        pub loader_valid: Cell<bool>,
     }
 
     #[glib::object_subclass]
@@ -101,6 +123,14 @@ mod imp {
                 snapshots_filter_switch: Default::default(),
                 betas_filter_switch: Default::default(),
                 alphas_filter_switch: Default::default(),
+                fabric_expander: Default::default(),
+                fabric_version_list: Default::default(),
+                forge_expander: Default::default(),
+                forge_version_list: Default::default(),
+                quilt_expander: Default::default(),
+                quilt_version_list: Default::default(),
+                neoforge_expander: Default::default(),
+                neoforge_version_list: Default::default(),
                 instance_dir_button: Default::default(),
                 instance_dir_label: Default::default(),
                 libraries_dir_button: Default::default(),
@@ -111,8 +141,10 @@ mod imp {
                 manifest: Default::default(),
                 version_list_store: list_store,
                 version_selection_model: selection,
+                loader_versions: Default::default(),
                 name_valid: Default::default(),
                 version_valid: Default::default(),
+                loader_valid: Cell::new(true),
             }
         }
 
@@ -183,6 +215,10 @@ impl BlockyNewInstanceDialog {
             instance_builder.description(description);
         }
 
+        if let Some(loader) = self.selected_loader() {
+            instance_builder.loader(loader);
+        }
+
         let instance_manager = BlockyInstanceManager::default();
         instance_manager.add_instance(instance_builder.build().unwrap());
 
@@ -321,6 +357,27 @@ impl BlockyNewInstanceDialog {
             ),
         );
 
+        // Mod loader
+        for kind in LoaderKind::all() {
+            let (expander, version_list) = self.loader_widgets(kind);
+
+            expander.connect_notify_local(
+                Some("enable-expansion"),
+                glib::clone!(@weak self as this => move |expander, _| {
+                    // Only one loader can be installed
+                    if expander.enables_expansion() {
+                        for other in LoaderKind::all().into_iter().filter(|other| *other != kind) {
+                            this.loader_widgets(other).0.set_enable_expansion(false);
+                        }
+                    }
+                    this.refresh_loader_versions(kind);
+                }),
+            );
+            version_list.connect_row_selected(glib::clone!(@weak self as this => move |_, _| {
+                this.set_loader_version(kind);
+            }));
+        }
+
         // Version filter
         imp.releases_filter_switch.connect_state_notify(
             glib::clone!(@weak self as this => move |_| {
@@ -358,6 +415,114 @@ impl BlockyNewInstanceDialog {
             imp.version_valid.set(false);
         }
 
+        for kind in LoaderKind::all() {
+            self.refresh_loader_versions(kind);
+        }
+    }
+
+    fn selected_game_version(&self) -> Option<String> {
+        let imp = imp::BlockyNewInstanceDialog::from_instance(self);
+
+        imp.version_selection_model
+            .selected_item()
+            .map(|item| item.downcast::<GVersionSummary>().unwrap().id())
+    }
+
+    fn loader_widgets(&self, kind: LoaderKind) -> (adw::ExpanderRow, gtk::ListBox) {
+        let imp = imp::BlockyNewInstanceDialog::from_instance(self);
+
+        match kind {
+            LoaderKind::Fabric => (imp.fabric_expander.get(), imp.fabric_version_list.get()),
+            LoaderKind::Forge => (imp.forge_expander.get(), imp.forge_version_list.get()),
+            LoaderKind::Quilt => (imp.quilt_expander.get(), imp.quilt_version_list.get()),
+            LoaderKind::NeoForge => (imp.neoforge_expander.get(), imp.neoforge_version_list.get()),
+        }
+    }
+
+    fn selected_loader(&self) -> Option<Loader> {
+        LoaderKind::all().into_iter().find_map(|kind| {
+            self.selected_loader_version(kind)
+                .map(|version| Loader { kind, version })
+        })
+    }
+
+    fn selected_loader_version(&self, kind: LoaderKind) -> Option<String> {
+        let imp = imp::BlockyNewInstanceDialog::from_instance(self);
+        let (expander, version_list) = self.loader_widgets(kind);
+
+        if !expander.enables_expansion() {
+            return None;
+        }
+
+        let row = version_list.selected_row()?;
+        imp.loader_versions
+            .borrow()
+            .get(&kind)?
+            .get(row.index() as usize)
+            .cloned()
+    }
+
+    fn refresh_loader_versions(&self, kind: LoaderKind) {
+        let imp = imp::BlockyNewInstanceDialog::from_instance(self);
+        let (expander, version_list) = self.loader_widgets(kind);
+
+        imp.loader_versions.borrow_mut().remove(&kind);
+        while let Some(row) = version_list.row_at_index(0) {
+            version_list.remove(&row);
+        }
+        self.set_loader_version(kind);
+
+        let game_version = match self.selected_game_version() {
+            Some(game_version) if expander.enables_expansion() => game_version,
+            _ => return,
+        };
+
+        fetch_loader_versions(kind, game_version.clone()).attach(
+            None,
+            glib::clone!(@weak self as this => @default-return glib::Continue(false),
+                move |versions| {
+                    let imp = imp::BlockyNewInstanceDialog::from_instance(&this);
+                    let (expander, version_list) = this.loader_widgets(kind);
+
+                    // The game version might have changed in the meantime
+                    if this.selected_game_version().as_ref() != Some(&game_version)
+                        || !expander.enables_expansion()
+                        || imp.loader_versions.borrow().contains_key(&kind)
+                    {
+                        return glib::Continue(false);
+                    }
+
+                    for version in &versions {
+                        let row = adw::ActionRow::new();
+                        row.set_title(version);
+                        version_list.append(&row);
+                    }
+                    imp.loader_versions.borrow_mut().insert(kind, versions);
+
+                    if let Some(row) = version_list.row_at_index(0) {
+                        version_list.select_row(Some(&row));
+                    }
+                    this.set_loader_version(kind);
+
+                    glib::Continue(false)
+                }
+            ),
+        );
+    }
+
+    fn set_loader_version(&self, kind: LoaderKind) {
+        let imp = imp::BlockyNewInstanceDialog::from_instance(self);
+        let (expander, _) = self.loader_widgets(kind);
+
+        expander.set_subtitle(&self.selected_loader_version(kind).unwrap_or_default());
+
+        // Every enabled loader needs a selected version
+        let loader_valid = LoaderKind::all().into_iter().all(|kind| {
+            !self.loader_widgets(kind).0.enables_expansion()
+                || self.selected_loader_version(kind).is_some()
+        });
+        imp.loader_valid.set(loader_valid);
+
         self.update_add_button();
     }
 
@@ -395,7 +560,7 @@ impl BlockyNewInstanceDialog {
     fn update_add_button(&self) {
         let imp = imp::BlockyNewInstanceDialog::from_instance(self);
 
-        let sensitive = imp.name_valid.get() && imp.version_valid.get();
+        let sensitive = imp.name_valid.get() && imp.version_valid.get() && imp.loader_valid.get();
         imp.add_button.set_sensitive(sensitive);
     }
 
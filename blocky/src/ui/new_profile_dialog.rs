@@ -1,4 +1,5 @@
 use crate::config;
+use crate::helpers;
 use crate::managers::BlockyProfileManager;
 use crate::ui::BlockyApplicationWindow;
 use crate::utils::update;
@@ -85,49 +86,54 @@ impl BlockyNewProfileDialog {
         );
 
         thread::spawn(move || {
+            let endpoints = helpers::endpoints();
+
             sender
                 .send(update::StatusUpdate::Update(gettext(
                     "Authenticating at Microsoft",
                 )))
                 .expect("Could not send through channel");
-            let mut profile =
-                Profile::authenticate_microsoft(config::MS_GRAPH_ID, config::MS_GRAPH_SECRET)
-                    .unwrap();
+            let mut profile = Profile::authenticate_microsoft(
+                config::MS_GRAPH_ID,
+                config::MS_GRAPH_SECRET,
+                &endpoints,
+            )
+            .unwrap();
 
             sender
                 .send(update::StatusUpdate::Update(gettext(
                     "Authenticating at XBox Live",
                 )))
                 .expect("Could not send through channel");
-            profile.authenticate_xbox_live().unwrap();
+            profile.authenticate_xbox_live(&endpoints).unwrap();
 
             sender
                 .send(update::StatusUpdate::Update(gettext(
                     "Authenticating at XBox Live Security",
                 )))
                 .expect("Could not send through channel");
-            profile.authenticate_xbox_live_security().unwrap();
+            profile.authenticate_xbox_live_security(&endpoints).unwrap();
 
             sender
                 .send(update::StatusUpdate::Update(gettext(
                     "Authenticating at Minecraft",
                 )))
                 .expect("Could not send through channel");
-            profile.authenticate_minecraft().unwrap();
+            profile.authenticate_minecraft(&endpoints).unwrap();
 
             sender
                 .send(update::StatusUpdate::Update(gettext(
                     "Getting Minecraft entitlements",
                 )))
                 .expect("Could not send through channel");
-            profile.set_entitlements().unwrap();
+            profile.set_entitlements(&endpoints).unwrap();
 
             sender
                 .send(update::StatusUpdate::Update(gettext(
                     "Getting Minecraft profile",
                 )))
                 .expect("Could not send through channel");
-            profile.set_profile().unwrap();
+            profile.set_profile(&endpoints).unwrap();
 
             sender
                 .send(update::StatusUpdate::Finish(profile))
@@ -27,6 +27,16 @@ mod imp {
         pub assets_dir_button: TemplateChild<gtk::Button>,
         #[template_child]
         pub assets_dir_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub store_dir_button: TemplateChild<gtk::Button>,
+        #[template_child]
+        pub store_dir_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub download_workers_spinbutton: TemplateChild<gtk::SpinButton>,
+        #[template_child]
+        pub metadata_max_age_spinbutton: TemplateChild<gtk::SpinButton>,
+        #[template_child]
+        pub enable_store_switch: TemplateChild<gtk::Switch>,
 
         // Minecraft Page
         #[template_child]
@@ -44,6 +54,8 @@ mod imp {
         #[template_child]
         pub max_memory_spinbutton: TemplateChild<gtk::SpinButton>,
         #[template_child]
+        pub use_managed_java_switch: TemplateChild<gtk::Switch>,
+        #[template_child]
         pub java_exec_button: TemplateChild<gtk::Button>,
         #[template_child]
         pub java_exec_label: TemplateChild<gtk::Label>,
@@ -118,6 +130,26 @@ impl BlockyPreferencesWindow {
             .connect_clicked(glib::clone!(@weak self as this => move |_| {
                 this.folder_chooser(&gettext("Select Assets Location"), SettingKey::AssetsDir);
             }));
+        // Store dir
+        settings::bind_property(SettingKey::StoreDir, &*imp.store_dir_label, "label");
+        imp.store_dir_button
+            .connect_clicked(glib::clone!(@weak self as this => move |_| {
+                this.folder_chooser(&gettext("Select Store Location"), SettingKey::StoreDir);
+            }));
+        // Download workers
+        settings::bind_property(
+            SettingKey::DownloadWorkers,
+            &*imp.download_workers_spinbutton,
+            "value",
+        );
+        // Metadata max age
+        settings::bind_property(
+            SettingKey::MetadataMaxAge,
+            &*imp.metadata_max_age_spinbutton,
+            "value",
+        );
+        // Enable store
+        settings::bind_property(SettingKey::EnableStore, &*imp.enable_store_switch, "state");
 
         // Minecraft Page
         // Fullscreen
@@ -150,6 +182,12 @@ impl BlockyPreferencesWindow {
         settings::bind_property(SettingKey::MinMemory, &*imp.min_memory_spinbutton, "value");
         // Maximum memory
         settings::bind_property(SettingKey::MaxMemory, &*imp.max_memory_spinbutton, "value");
+        // Use managed Java
+        settings::bind_property(
+            SettingKey::UseManagedJava,
+            &*imp.use_managed_java_switch,
+            "state",
+        );
         // Java exec
         settings::bind_property(SettingKey::JavaExec, &*imp.java_exec_label, "label");
         imp.java_exec_button
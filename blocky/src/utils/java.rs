@@ -0,0 +1,17 @@
+use crate::helpers;
+use blocky_core::instance::Instance;
+use blocky_core::java::models::java_installation::{JavaCompatibility, JavaInstallation};
+use std::thread;
+
+pub fn fetch_java(
+    instance: Instance,
+) -> glib::Receiver<Vec<(JavaInstallation, Option<JavaCompatibility>)>> {
+    let (sender, receiver) = glib::MainContext::channel(glib::PRIORITY_DEFAULT);
+    thread::spawn(move || {
+        sender
+            .send(helpers::find_java(&instance))
+            .expect("Could not send Java installations through channel");
+    });
+
+    receiver
+}
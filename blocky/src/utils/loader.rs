@@ -0,0 +1,27 @@
+use crate::helpers;
+use blocky_core::loader::models::loader::LoaderKind;
+use std::thread;
+
+pub fn fetch_loader_versions(
+    kind: LoaderKind,
+    game_version: String,
+) -> glib::Receiver<Vec<String>> {
+    let (sender, receiver) = glib::MainContext::channel(glib::PRIORITY_DEFAULT);
+    thread::spawn(
+        move || match helpers::get_loader_versions(kind, &game_version) {
+            Ok(versions) => {
+                sender
+                    .send(versions)
+                    .expect("Could not send loader versions through channel");
+            }
+            Err(err) => {
+                error!("Error while getting loader versions: {}", err);
+                sender
+                    .send(vec![])
+                    .expect("Could not send loader versions through channel");
+            }
+        },
+    );
+
+    receiver
+}
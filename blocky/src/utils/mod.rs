@@ -1,2 +1,5 @@
+pub mod java;
+pub mod loader;
+pub mod modrinth;
 pub mod update;
 pub mod version_summary;
@@ -0,0 +1,88 @@
+use crate::helpers;
+use blocky_core::instance::Instance;
+use blocky_core::modrinth::models::installed_mod::InstalledMod;
+use blocky_core::modrinth::models::project::SearchHit;
+use blocky_core::modrinth::resolve::Resolution;
+use std::thread;
+
+pub fn fetch_installed_mods(instance: Instance) -> glib::Receiver<Vec<InstalledMod>> {
+    let (sender, receiver) = glib::MainContext::channel(glib::PRIORITY_DEFAULT);
+    thread::spawn(move || match helpers::get_installed_mods(&instance) {
+        Ok(mods) => {
+            sender
+                .send(mods)
+                .expect("Could not send installed mods through channel");
+        }
+        Err(err) => {
+            error!("Error while getting installed mods: {}", err);
+            sender
+                .send(vec![])
+                .expect("Could not send installed mods through channel");
+        }
+    });
+
+    receiver
+}
+
+// Receives the error message if the search failed, so it is not mistaken for no results
+pub fn fetch_mod_search(
+    instance: Instance,
+    query: String,
+) -> glib::Receiver<Result<Vec<SearchHit>, String>> {
+    let (sender, receiver) = glib::MainContext::channel(glib::PRIORITY_DEFAULT);
+    thread::spawn(move || match helpers::search_mods(&instance, &query) {
+        Ok(hits) => {
+            sender
+                .send(Ok(hits))
+                .expect("Could not send search results through channel");
+        }
+        Err(err) => {
+            error!("Error while searching mods: {}", err);
+            sender
+                .send(Err(err.to_string()))
+                .expect("Could not send search results through channel");
+        }
+    });
+
+    receiver
+}
+
+// Receives `None` if the mod could not be resolved
+pub fn resolve_mod(instance: Instance, project_id: String) -> glib::Receiver<Option<Resolution>> {
+    let (sender, receiver) = glib::MainContext::channel(glib::PRIORITY_DEFAULT);
+    thread::spawn(move || match helpers::resolve_mod(&instance, &project_id) {
+        Ok(resolution) => {
+            sender
+                .send(Some(resolution))
+                .expect("Could not send resolved mod through channel");
+        }
+        Err(err) => {
+            error!("Error while resolving mod: {}", err);
+            sender
+                .send(None)
+                .expect("Could not send resolved mod through channel");
+        }
+    });
+
+    receiver
+}
+
+// Receives `false` if the installation failed
+pub fn install_mod(instance: Instance, resolution: Resolution) -> glib::Receiver<bool> {
+    let (sender, receiver) = glib::MainContext::channel(glib::PRIORITY_DEFAULT);
+    thread::spawn(move || match helpers::install_mod(&instance, &resolution) {
+        Ok(_) => {
+            sender
+                .send(true)
+                .expect("Could not send installed mod through channel");
+        }
+        Err(err) => {
+            error!("Error while installing mod: {}", err);
+            sender
+                .send(false)
+                .expect("Could not send installed mod through channel");
+        }
+    });
+
+    receiver
+}